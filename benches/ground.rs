@@ -0,0 +1,131 @@
+//! Benchmarks of ray casting against a large map made of many separate `Ground` chunks, like
+//! chunked terrain, with and without the grid that skips chunks far from each ray. Run with
+//! `cargo bench --features test_utils --bench ground`.
+
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use bevy::render::mesh::{MeshAabb, VertexAttributeValues};
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::{CameraBounds, Ground, RtsCamera, RtsCameraGround};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::f32::consts::TAU;
+
+/// How many chunks there are along each side of the map.
+const CHUNKS: i32 = 50;
+/// The width of each chunk.
+const CHUNK_SIZE: f32 = 8.0;
+/// How many cameras follow the ground.
+const CAMERAS: usize = 4;
+/// How many cursor rays are cast per frame in the grab pan benchmark.
+const GRAB_RAYS: usize = 16;
+
+/// Creates an app with the chunked map, with or without the ground index.
+fn map_app(indexed: bool) -> RtsCameraTestApp {
+    let mut app = RtsCameraTestApp::new();
+    if !indexed {
+        app.remove_ground_index();
+    }
+    app.init_resource::<Assets<Mesh>>();
+    let half_map = CHUNKS as f32 * CHUNK_SIZE / 2.0;
+    for x in 0..CHUNKS {
+        for z in 0..CHUNKS {
+            let origin = Vec2::new(
+                x as f32 * CHUNK_SIZE - half_map,
+                z as f32 * CHUNK_SIZE - half_map,
+            );
+            // Rolling hills, so every ray has to find the actual triangle it hits
+            let mut mesh = Plane3d::default()
+                .mesh()
+                .size(CHUNK_SIZE, CHUNK_SIZE)
+                .subdivisions(7)
+                .build();
+            if let Some(VertexAttributeValues::Float32x3(positions)) =
+                mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+            {
+                for position in positions.iter_mut() {
+                    let world = origin + Vec2::new(position[0], position[2]);
+                    position[1] = (world.x * 0.05).sin() * (world.y * 0.05).cos() * 4.0;
+                }
+            }
+            // There's no rendering to compute the bounds, global transforms, or visibility
+            let aabb = mesh.compute_aabb().expect("chunk should have positions");
+            let transform = Transform::from_xyz(origin.x, 0.0, origin.y);
+            let mesh = app.world_mut().resource_mut::<Assets<Mesh>>().add(mesh);
+            app.world_mut().spawn((
+                Mesh3d(mesh),
+                transform,
+                GlobalTransform::from(transform),
+                aabb,
+                InheritedVisibility::VISIBLE,
+                Ground,
+            ));
+        }
+    }
+    let bounds = CameraBounds::Aabb(bevy::math::bounding::Aabb2d::new(
+        Vec2::ZERO,
+        Vec2::splat(half_map),
+    ));
+    for _ in 0..CAMERAS {
+        app.spawn_camera(RtsCamera {
+            bounds: bounds.clone(),
+            ..default()
+        });
+    }
+    // Let the ground be marked and indexed
+    app.step(1);
+    app
+}
+
+/// Moves each camera to a new spot on the map, so the ground below it has to be found again.
+fn wander(mut cam_q: Query<&mut RtsCamera>, mut frame: Local<u32>) {
+    *frame += 1;
+    for (i, mut cam) in cam_q.iter_mut().enumerate() {
+        let angle = *frame as f32 * 0.01 + i as f32 * TAU / CAMERAS as f32;
+        let radius = 40.0 + i as f32 * 30.0;
+        cam.target_focus.translation = Vec3::new(angle.cos(), 0.0, angle.sin()) * radius;
+    }
+}
+
+fn follow_ground(c: &mut Criterion) {
+    let mut group = c.benchmark_group("follow_ground");
+    for indexed in [true, false] {
+        let mut app = map_app(indexed);
+        app.add_systems(Update, wander);
+        let label = if indexed { "indexed" } else { "unindexed" };
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            b.iter(|| app.update())
+        });
+    }
+    group.finish();
+}
+
+/// Casts rays from each camera through a spread of cursor positions, like grab pan does when a
+/// drag starts.
+fn cast_cursor_rays(cam_q: Query<(&Transform, &RtsCamera)>, ground: RtsCameraGround) {
+    for (tfm, _) in cam_q.iter() {
+        for i in 0..GRAB_RAYS {
+            let spread = (i as f32 / GRAB_RAYS as f32 - 0.5) * 0.8;
+            let direction = tfm.rotation * Vec3::new(spread, spread * 0.5, -1.0);
+            let ray = Ray3d::new(tfm.translation, Dir3::new(direction).unwrap());
+            std::hint::black_box(ground.cast_ray(ray, None));
+        }
+    }
+}
+
+fn grab_pan_rays(c: &mut Criterion) {
+    let mut group = c.benchmark_group("grab_pan_rays");
+    for indexed in [true, false] {
+        let mut app = map_app(indexed);
+        app.add_systems(Update, wander);
+        app.step(1);
+        let system: SystemId = app.world_mut().register_system(cast_cursor_rays);
+        let label = if indexed { "indexed" } else { "unindexed" };
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            b.iter(|| app.world_mut().run_system(system).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, follow_ground, grab_pan_rays);
+criterion_main!(benches);
@@ -5,12 +5,15 @@ use std::f32::consts::TAU;
 
 use bevy::prelude::*;
 
-use bevy_rts_camera::{Ground, RtsCamera, RtsCameraControls, RtsCameraPlugin, RtsCameraSystemSet};
+use bevy_rts_camera::{
+    EdgePanWidths, Ground, RtsCamera, RtsCameraControls, RtsCameraPlugin, RtsCameraSnap,
+    RtsCameraSystemSet,
+};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(RtsCameraPlugin)
+        .add_plugins(RtsCameraPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(
             Update,
@@ -104,8 +107,10 @@ Press T to toggle controls (K and L will still work)",
             height_max: 50.0,
             // Change the angle of the camera to 35 degrees
             min_angle: 35.0f32.to_radians(),
-            // Decrease smoothing
-            smoothness: 0.1,
+            // Decrease smoothing of panning
+            pan_smoothness: 0.1,
+            // Make rotation snappy
+            rotate_smoothness: 0.05,
             // Change starting position
             target_focus: Transform::from_xyz(3.0, 0.0, -3.0),
             // Change starting zoom level
@@ -115,11 +120,11 @@ Press T to toggle controls (K and L will still work)",
             ..default()
         },
         RtsCameraControls {
-            // Change pan controls to WASD
-            key_up: KeyCode::KeyW,
-            key_down: KeyCode::KeyS,
-            key_left: KeyCode::KeyA,
-            key_right: KeyCode::KeyD,
+            // Pan with WASD as well as the arrow keys
+            keys_up: vec![KeyCode::KeyW, KeyCode::ArrowUp],
+            keys_down: vec![KeyCode::KeyS, KeyCode::ArrowDown],
+            keys_left: vec![KeyCode::KeyA, KeyCode::ArrowLeft],
+            keys_right: vec![KeyCode::KeyD, KeyCode::ArrowRight],
             // Rotate the camera with right click
             button_rotate: MouseButton::Right,
             // Keep the mouse cursor in place when rotating
@@ -129,7 +134,7 @@ Press T to toggle controls (K and L will still work)",
             // Keep the mouse cursor in place when dragging
             lock_on_drag: true,
             // Change the width of the area that triggers edge pan. 0.1 is 10% of the window height.
-            edge_pan_width: 0.1,
+            edge_pan_width: EdgePanWidths::all(0.1),
             // Increase pan speed
             pan_speed: 25.0,
             ..default()
@@ -162,7 +167,7 @@ fn lock_or_jump(
         for mut cam in cam_q.iter_mut() {
             if key_input.pressed(KeyCode::KeyL) {
                 cam.target_focus.translation = cube.translation;
-                cam.snap = true;
+                cam.snap = RtsCameraSnap::TRANSLATION;
             }
             if key_input.just_pressed(KeyCode::KeyK) {
                 cam.target_focus.translation = cube.translation;
@@ -0,0 +1,100 @@
+//! Drives the camera entirely through `RtsCameraInput` events instead of `RtsCameraControls`, as
+//! you would when using an input manager. Here the input is read from the keyboard directly, but
+//! it could just as easily come from a gamepad or an action map.
+//!
+//! - WASD: pan
+//! - Q/E: rotate
+//! - R/F: tilt
+//! - Z/X: zoom
+
+use bevy::prelude::*;
+
+use bevy_rts_camera::{Ground, RtsCamera, RtsCameraInput, RtsCameraInputKind, RtsCameraPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(RtsCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, send_camera_input)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Ground
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(80.0, 80.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+        Ground,
+    ));
+    // Some generic units that are not part of the 'Ground' (ignored for height calculation)
+    let unit_material = materials.add(Color::srgb(0.8, 0.7, 0.6));
+    for x in -5..5 {
+        for z in -5..5 {
+            commands.spawn((
+                Mesh3d(meshes.add(Capsule3d::new(0.25, 1.25))),
+                MeshMaterial3d(unit_material.clone()),
+                Transform::from_xyz(x as f32 * 0.7, 0.75, z as f32 * 0.7),
+            ));
+        }
+    }
+    // Light
+    commands.spawn((
+        DirectionalLight {
+            illuminance: 1000.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_rotation(Quat::from_euler(
+            EulerRot::YXZ,
+            150.0f32.to_radians(),
+            -40.0f32.to_radians(),
+            0.0,
+        )),
+    ));
+    // Camera, without `RtsCameraControls`. Tilting works best without `dynamic_angle`, so the
+    // angle only changes when we ask it to.
+    commands.spawn(RtsCamera {
+        dynamic_angle: false,
+        ..default()
+    });
+}
+
+fn send_camera_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut events: EventWriter<RtsCameraInput>,
+) {
+    let axis = |positive: KeyCode, negative: KeyCode| {
+        keys.pressed(positive) as i8 as f32 - keys.pressed(negative) as i8 as f32
+    };
+    let delta = time.delta_secs();
+
+    let pan = Vec2::new(
+        axis(KeyCode::KeyD, KeyCode::KeyA),
+        axis(KeyCode::KeyW, KeyCode::KeyS),
+    );
+    if pan != Vec2::ZERO {
+        events.send(RtsCameraInput::all(RtsCameraInputKind::Pan(
+            pan.normalize() * 15.0 * delta,
+        )));
+    }
+    let rotate = axis(KeyCode::KeyQ, KeyCode::KeyE);
+    if rotate != 0.0 {
+        events.send(RtsCameraInput::all(RtsCameraInputKind::Rotate(
+            rotate * 2.0 * delta,
+        )));
+    }
+    let tilt = axis(KeyCode::KeyR, KeyCode::KeyF);
+    if tilt != 0.0 {
+        events.send(RtsCameraInput::all(RtsCameraInputKind::Tilt(tilt * delta)));
+    }
+    let zoom = axis(KeyCode::KeyZ, KeyCode::KeyX);
+    if zoom != 0.0 {
+        events.send(RtsCameraInput::all(RtsCameraInputKind::Zoom(zoom * delta)));
+    }
+}
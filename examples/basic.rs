@@ -8,7 +8,7 @@ use bevy_rts_camera::{Ground, RtsCamera, RtsCameraControls, RtsCameraPlugin};
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(RtsCameraPlugin)
+        .add_plugins(RtsCameraPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
@@ -0,0 +1,137 @@
+//! A stress test with a large map made of 2,500 separate `Ground` chunks, like chunked terrain, and
+//! several cameras in split screen. The frame time is logged to the console, which is useful to
+//! check the cost of ground following when making changes. Run it with `--release`.
+
+use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::window::WindowResized;
+
+use bevy_rts_camera::{
+    CameraBounds, Ground, GroundSampling, RtsCamera, RtsCameraControls, RtsCameraPlugin,
+};
+
+/// How many chunks there are along each side of the map.
+const CHUNKS: i32 = 50;
+/// The width of each chunk.
+const CHUNK_SIZE: f32 = 8.0;
+/// How many cameras to split the screen between.
+const CAMERAS: u32 = 4;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            FrameTimeDiagnosticsPlugin,
+            LogDiagnosticsPlugin::default(),
+        ))
+        .add_plugins(RtsCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (set_viewports, wander))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Each chunk is its own mesh, with rolling hills so ground following has work to do
+    let materials = [
+        materials.add(Color::srgb(0.3, 0.5, 0.3)),
+        materials.add(Color::srgb(0.35, 0.55, 0.3)),
+    ];
+    let half_map = CHUNKS as f32 * CHUNK_SIZE / 2.0;
+    for x in 0..CHUNKS {
+        for z in 0..CHUNKS {
+            let origin = Vec2::new(
+                x as f32 * CHUNK_SIZE - half_map,
+                z as f32 * CHUNK_SIZE - half_map,
+            );
+            let mut mesh = Plane3d::default()
+                .mesh()
+                .size(CHUNK_SIZE, CHUNK_SIZE)
+                .subdivisions(7)
+                .build();
+            if let Some(bevy::render::mesh::VertexAttributeValues::Float32x3(positions)) =
+                mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+            {
+                for position in positions.iter_mut() {
+                    let world = origin + Vec2::new(position[0], position[2]);
+                    position[1] = (world.x * 0.05).sin() * (world.y * 0.05).cos() * 4.0;
+                }
+            }
+            mesh.compute_normals();
+            commands.spawn((
+                Mesh3d(meshes.add(mesh)),
+                MeshMaterial3d(materials[((x + z) % 2) as usize].clone()),
+                Transform::from_xyz(origin.x, 0.0, origin.y),
+                Ground,
+            ));
+        }
+    }
+    // Light
+    commands.spawn((
+        DirectionalLight {
+            illuminance: 1000.0,
+            ..default()
+        },
+        Transform::from_rotation(Quat::from_euler(
+            EulerRot::YXZ,
+            150.0f32.to_radians(),
+            -40.0f32.to_radians(),
+            0.0,
+        )),
+    ));
+    // Cameras, the first of which can be controlled
+    let bounds = CameraBounds::Aabb(bevy::math::bounding::Aabb2d::new(
+        Vec2::ZERO,
+        Vec2::splat(half_map),
+    ));
+    for i in 0..CAMERAS {
+        let mut entity = commands.spawn((
+            RtsCamera {
+                bounds: bounds.clone(),
+                ground_sampling: GroundSampling::Average { spread: 0.1 },
+                ..default()
+            },
+            Camera {
+                order: i as isize,
+                ..default()
+            },
+        ));
+        if i == 0 {
+            entity.insert(RtsCameraControls::default());
+        }
+    }
+}
+
+/// Splits the window into a column for each camera.
+fn set_viewports(
+    windows: Query<&Window>,
+    mut resize_events: EventReader<WindowResized>,
+    mut cam_q: Query<&mut Camera, With<RtsCamera>>,
+) {
+    for resize_event in resize_events.read() {
+        let Ok(window) = windows.get(resize_event.window) else {
+            continue;
+        };
+        let size = window.physical_size();
+        let width = size.x / CAMERAS;
+        for (i, mut camera) in cam_q.iter_mut().enumerate() {
+            camera.viewport = Some(Viewport {
+                physical_position: UVec2::new(width * i as u32, 0),
+                physical_size: UVec2::new(width, size.y),
+                ..default()
+            });
+        }
+    }
+}
+
+/// Keeps the cameras without controls moving around the map, so they keep casting rays.
+fn wander(mut cam_q: Query<&mut RtsCamera, Without<RtsCameraControls>>, time: Res<Time>) {
+    for (i, mut cam) in cam_q.iter_mut().enumerate() {
+        let t = time.elapsed_secs() * 0.2 + i as f32;
+        cam.target_focus.translation = Vec3::new(t.sin(), 0.0, (t * 0.7).cos()) * 150.0;
+    }
+}
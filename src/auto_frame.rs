@@ -0,0 +1,86 @@
+use crate::director::DirectedCamera;
+use crate::RtsCamera;
+use bevy::prelude::*;
+use std::ops::RangeInclusive;
+
+/// Marker component for entities that cameras with `RtsCameraAutoFrame` keep in view, e.g. the
+/// player's current selection.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq)]
+pub struct CameraFramed;
+
+/// Component that makes an `RtsCamera` continuously pan and zoom to keep every entity marked with
+/// `CameraFramed` on screen (see `RtsCamera::frame_points`), like the spectator cameras of some
+/// MOBAs. Zooming out to keep entities in view happens straight away, but the camera only zooms
+/// back in or recenters once the framing has changed by more than the hysteresis, so it doesn't
+/// constantly drift as the entities move around. Does nothing while no entities are framed.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{CameraFramed, RtsCamera, RtsCameraAutoFrame};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((RtsCamera::default(), RtsCameraAutoFrame::default()));
+/// }
+///
+/// fn select(mut commands: Commands, unit: Entity) {
+///     commands.entity(unit).insert(CameraFramed);
+/// }
+/// ```
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct RtsCameraAutoFrame {
+    /// The fraction of the view kept clear around the framed entities on each side.
+    /// Defaults to `0.15`.
+    pub padding: f32,
+    /// The range that `RtsCamera.target_zoom` is kept within, e.g. to stop the camera zooming all
+    /// the way in on a single unit.
+    /// Defaults to `0.0..=0.8`.
+    pub zoom: RangeInclusive<f32>,
+    /// How much further the camera could zoom in before it does.
+    /// Defaults to `0.1`.
+    pub zoom_hysteresis: f32,
+    /// How far (in world units) the center of the framed entities can move from the focus before
+    /// the camera recenters on them, as long as they still fit on screen.
+    /// Defaults to `2.0`.
+    pub focus_hysteresis: f32,
+}
+
+impl Default for RtsCameraAutoFrame {
+    fn default() -> Self {
+        RtsCameraAutoFrame {
+            padding: 0.15,
+            zoom: 0.0..=0.8,
+            zoom_hysteresis: 0.1,
+            focus_hysteresis: 2.0,
+        }
+    }
+}
+
+pub(crate) fn auto_frame(
+    mut cam_q: Query<(&mut RtsCamera, &RtsCameraAutoFrame, &Projection), Without<DirectedCamera>>,
+    framed_q: Query<&GlobalTransform, With<CameraFramed>>,
+) {
+    if framed_q.is_empty() {
+        return;
+    }
+    let points = framed_q
+        .iter()
+        .map(|tfm| tfm.translation())
+        .collect::<Vec<_>>();
+    for (mut cam, auto_frame, projection) in cam_q.iter_mut() {
+        let Some((focus, zoom)) = cam.framing(&points, auto_frame.padding, projection) else {
+            continue;
+        };
+        let zoom = zoom.clamp(*auto_frame.zoom.start(), *auto_frame.zoom.end());
+        // Zooming out can't wait, as some entities would be off screen otherwise
+        let rezoom = zoom < cam.target_zoom || zoom > cam.target_zoom + auto_frame.zoom_hysteresis;
+        if rezoom && cam.target_zoom != zoom {
+            cam.target_zoom = zoom;
+        }
+
+        let offset = cam.world_to_plane(focus.translation - cam.target_focus.translation);
+        if rezoom || offset.xz().length() > auto_frame.focus_hysteresis {
+            // Leave the height to ground following
+            cam.target_focus.translation =
+                cam.with_height_of(focus.translation, cam.target_focus.translation);
+        }
+    }
+}
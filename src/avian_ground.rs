@@ -0,0 +1,75 @@
+use crate::ground::GroundMesh;
+use crate::{GroundBackend, RtsCameraRayFilter};
+use avian3d::prelude::{SpatialQueryFilter, SpatialQueryPipeline};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// `GroundBackend` that ray casts against avian3d colliders instead of meshes. Only colliders on
+/// `Ground` entities (or below them in the hierarchy) are hit, following `GroundLayers`,
+/// `NotGround`, and `RtsCameraRayFilter` like the default backend. The colliders are where avian's
+/// `SpatialQueryPipeline` last saw them, which is updated every physics step.
+/// Requires the `avian3d` feature.
+/// # Example
+/// ```no_run
+/// # use avian3d::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{AvianGround, Ground, RtsCameraPlugin};
+/// # fn main() {
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         PhysicsPlugins::default(),
+///         RtsCameraPlugin::default().with_ground_backend::<AvianGround>(),
+///     ))
+///     .add_systems(Startup, setup)
+///     .run();
+/// # }
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         RigidBody::Static,
+///         Collider::cuboid(100.0, 1.0, 100.0),
+///         Transform::from_xyz(0.0, -0.5, 0.0),
+///         Ground,
+///     ));
+/// }
+/// ```
+pub struct AvianGround;
+
+/// The system param used by `AvianGround`.
+#[derive(SystemParam)]
+pub struct AvianGroundParam<'w, 's> {
+    pipeline: Option<Res<'w, SpatialQueryPipeline>>,
+    mesh_q: Query<'w, 's, &'static GroundMesh>,
+}
+
+impl GroundBackend for AvianGround {
+    type Param = AvianGroundParam<'static, 'static>;
+
+    fn cast_rays(
+        param: &AvianGroundParam,
+        rays: &[(Ray3d, Option<&RtsCameraRayFilter>)],
+    ) -> Vec<Option<Vec3>> {
+        let Some(pipeline) = &param.pipeline else {
+            return vec![None; rays.len()];
+        };
+        rays.iter()
+            .map(|(ray, filter)| {
+                pipeline
+                    .cast_ray_predicate(
+                        ray.origin,
+                        ray.direction,
+                        f32::MAX,
+                        true,
+                        &SpatialQueryFilter::default(),
+                        &|entity| {
+                            param
+                                .mesh_q
+                                .get(entity)
+                                .is_ok_and(|mesh| mesh.accepts(entity, *filter))
+                        },
+                    )
+                    .map(|hit| ray.get_point(hit.distance))
+            })
+            .collect()
+    }
+}
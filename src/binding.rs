@@ -0,0 +1,287 @@
+use crate::RtsCameraControls;
+use bevy::prelude::*;
+
+/// An action of `RtsCameraControls` that can be rebound with `RtsCameraControls::rebind`, e.g.
+/// from an in-game options screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RtsCameraAction {
+    /// `RtsCameraControls.keys_up`.
+    PanUp,
+    /// `RtsCameraControls.keys_down`.
+    PanDown,
+    /// `RtsCameraControls.keys_left`.
+    PanLeft,
+    /// `RtsCameraControls.keys_right`.
+    PanRight,
+    /// `RtsCameraControls.keys_fast_pan`.
+    FastPan,
+    /// `RtsCameraControls.keys_rotate_left`.
+    RotateLeft,
+    /// `RtsCameraControls.keys_rotate_right`.
+    RotateRight,
+    /// `RtsCameraControls.keys_rotate_step_left`.
+    RotateStepLeft,
+    /// `RtsCameraControls.keys_rotate_step_right`.
+    RotateStepRight,
+    /// `RtsCameraControls.key_reset_orientation`.
+    ResetOrientation,
+    /// `RtsCameraControls.key_zoom_in`.
+    ZoomIn,
+    /// `RtsCameraControls.key_zoom_out`.
+    ZoomOut,
+    /// `RtsCameraControls.key_zoom_reset`.
+    ZoomReset,
+    /// `RtsCameraControls.keys_tilt_up`.
+    TiltUp,
+    /// `RtsCameraControls.keys_tilt_down`.
+    TiltDown,
+    /// `RtsCameraControls.modifiers_scroll_tilt`.
+    ScrollTilt,
+    /// `RtsCameraControls.button_rotate`. Can't be unbound.
+    MouseRotate,
+    /// `RtsCameraControls.button_drag`.
+    MouseDrag,
+    /// `RtsCameraControls.key_toggle_drag_mode`.
+    ToggleDragMode,
+    /// `RtsCameraControls.key_jump_to_hotspot`.
+    JumpToHotspot,
+    /// `RtsCameraControls.key_save_bookmark`. Can't be unbound.
+    SaveBookmark,
+    /// The key in `RtsCameraControls.keys_bookmark` for this slot. Can be rebound, but not
+    /// unbound, as slots are numbered by their position in `keys_bookmark`.
+    Bookmark(usize),
+}
+
+impl RtsCameraAction {
+    /// Every action, except `Bookmark`, as the number of bookmarks depends on the controls. Use
+    /// `RtsCameraControls::actions` to include those.
+    pub const ALL: [RtsCameraAction; 21] = [
+        RtsCameraAction::PanUp,
+        RtsCameraAction::PanDown,
+        RtsCameraAction::PanLeft,
+        RtsCameraAction::PanRight,
+        RtsCameraAction::FastPan,
+        RtsCameraAction::RotateLeft,
+        RtsCameraAction::RotateRight,
+        RtsCameraAction::RotateStepLeft,
+        RtsCameraAction::RotateStepRight,
+        RtsCameraAction::ResetOrientation,
+        RtsCameraAction::ZoomIn,
+        RtsCameraAction::ZoomOut,
+        RtsCameraAction::ZoomReset,
+        RtsCameraAction::TiltUp,
+        RtsCameraAction::TiltDown,
+        RtsCameraAction::ScrollTilt,
+        RtsCameraAction::MouseRotate,
+        RtsCameraAction::MouseDrag,
+        RtsCameraAction::ToggleDragMode,
+        RtsCameraAction::JumpToHotspot,
+        RtsCameraAction::SaveBookmark,
+    ];
+
+    /// Returns whether this action is bound to mouse buttons, rather than keys.
+    pub fn uses_mouse(self) -> bool {
+        matches!(
+            self,
+            RtsCameraAction::MouseRotate | RtsCameraAction::MouseDrag
+        )
+    }
+}
+
+/// An input that an `RtsCameraAction` can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlBinding {
+    /// A key on the keyboard.
+    Key(KeyCode),
+    /// A mouse button.
+    Mouse(MouseButton),
+}
+
+/// Why `RtsCameraControls::rebind` or `RtsCameraControls::unbind` failed. The controls are left
+/// unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebindError {
+    /// The action takes a different kind of input, e.g. a key for `RtsCameraAction::MouseRotate`.
+    WrongKind,
+    /// The binding is already used by this other action. Unbind or rebind that first.
+    Conflict(RtsCameraAction),
+    /// The action must always be bound to something.
+    Required,
+    /// There's no bookmark with this slot in `RtsCameraControls.keys_bookmark`.
+    InvalidBookmarkSlot(usize),
+}
+
+impl std::fmt::Display for RebindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongKind => write!(f, "the action can't be bound to this kind of input"),
+            Self::Conflict(action) => write!(f, "the binding is already used by {action:?}"),
+            Self::Required => write!(f, "the action must be bound to something"),
+            Self::InvalidBookmarkSlot(slot) => write!(f, "there is no bookmark slot {slot}"),
+        }
+    }
+}
+
+impl std::error::Error for RebindError {}
+
+impl RtsCameraControls {
+    /// Returns every action of these controls, including a `RtsCameraAction::Bookmark` for each of
+    /// `keys_bookmark`, e.g. to list them on an options screen.
+    pub fn actions(&self) -> impl Iterator<Item = RtsCameraAction> {
+        RtsCameraAction::ALL
+            .into_iter()
+            .chain((0..self.keys_bookmark.len()).map(RtsCameraAction::Bookmark))
+    }
+
+    /// Returns what `action` is currently bound to. Empty if it's unbound.
+    pub fn bindings(&self, action: RtsCameraAction) -> Vec<ControlBinding> {
+        let keys = |keys: &[KeyCode]| keys.iter().copied().map(ControlBinding::Key).collect();
+        match action {
+            RtsCameraAction::PanUp => keys(&self.keys_up),
+            RtsCameraAction::PanDown => keys(&self.keys_down),
+            RtsCameraAction::PanLeft => keys(&self.keys_left),
+            RtsCameraAction::PanRight => keys(&self.keys_right),
+            RtsCameraAction::FastPan => keys(&self.keys_fast_pan),
+            RtsCameraAction::RotateLeft => keys(&self.keys_rotate_left),
+            RtsCameraAction::RotateRight => keys(&self.keys_rotate_right),
+            RtsCameraAction::RotateStepLeft => keys(&self.keys_rotate_step_left),
+            RtsCameraAction::RotateStepRight => keys(&self.keys_rotate_step_right),
+            RtsCameraAction::ResetOrientation => keys(self.key_reset_orientation.as_slice()),
+            RtsCameraAction::ZoomIn => keys(self.key_zoom_in.as_slice()),
+            RtsCameraAction::ZoomOut => keys(self.key_zoom_out.as_slice()),
+            RtsCameraAction::ZoomReset => keys(self.key_zoom_reset.as_slice()),
+            RtsCameraAction::TiltUp => keys(&self.keys_tilt_up),
+            RtsCameraAction::TiltDown => keys(&self.keys_tilt_down),
+            RtsCameraAction::ScrollTilt => keys(&self.modifiers_scroll_tilt),
+            RtsCameraAction::MouseRotate => vec![ControlBinding::Mouse(self.button_rotate)],
+            RtsCameraAction::MouseDrag => self
+                .button_drag
+                .into_iter()
+                .map(ControlBinding::Mouse)
+                .collect(),
+            RtsCameraAction::ToggleDragMode => keys(self.key_toggle_drag_mode.as_slice()),
+            RtsCameraAction::JumpToHotspot => keys(self.key_jump_to_hotspot.as_slice()),
+            RtsCameraAction::SaveBookmark => vec![ControlBinding::Key(self.key_save_bookmark)],
+            RtsCameraAction::Bookmark(slot) => {
+                keys(self.keys_bookmark.get(slot..=slot).unwrap_or_default())
+            }
+        }
+    }
+
+    /// Returns the action that `binding` is bound to, if any.
+    pub fn action_for(&self, binding: ControlBinding) -> Option<RtsCameraAction> {
+        self.actions()
+            .find(|action| self.bindings(*action).contains(&binding))
+    }
+
+    /// Binds `action` to `binding`, replacing whatever it was bound to before. Fails if `binding`
+    /// is the wrong kind of input for `action`, or is already used by another action.
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::{ControlBinding, RebindError, RtsCameraAction, RtsCameraControls};
+    /// let mut controls = RtsCameraControls::default();
+    /// controls
+    ///     .rebind(RtsCameraAction::PanUp, ControlBinding::Key(KeyCode::KeyW))
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     controls.bindings(RtsCameraAction::PanUp),
+    ///     vec![ControlBinding::Key(KeyCode::KeyW)]
+    /// );
+    /// // Q already rotates the camera left
+    /// assert_eq!(
+    ///     controls.rebind(RtsCameraAction::PanLeft, ControlBinding::Key(KeyCode::KeyQ)),
+    ///     Err(RebindError::Conflict(RtsCameraAction::RotateLeft))
+    /// );
+    /// ```
+    pub fn rebind(
+        &mut self,
+        action: RtsCameraAction,
+        binding: ControlBinding,
+    ) -> Result<(), RebindError> {
+        if let Some(other) = self.action_for(binding).filter(|other| *other != action) {
+            return Err(RebindError::Conflict(other));
+        }
+        match (binding, action.uses_mouse()) {
+            (ControlBinding::Mouse(button), true) => {
+                if action == RtsCameraAction::MouseRotate {
+                    self.button_rotate = button;
+                } else {
+                    self.button_drag = Some(button);
+                }
+                Ok(())
+            }
+            (ControlBinding::Key(key), false) => {
+                if let RtsCameraAction::Bookmark(slot) = action {
+                    let bookmark = self
+                        .keys_bookmark
+                        .get_mut(slot)
+                        .ok_or(RebindError::InvalidBookmarkSlot(slot))?;
+                    *bookmark = key;
+                } else if action == RtsCameraAction::SaveBookmark {
+                    self.key_save_bookmark = key;
+                } else if let Some(keys) = self.keys_mut(action) {
+                    *keys = vec![key];
+                } else if let Some(key_option) = self.key_option_mut(action) {
+                    *key_option = Some(key);
+                }
+                Ok(())
+            }
+            _ => Err(RebindError::WrongKind),
+        }
+    }
+
+    /// Removes all bindings of `action`, so it can't be used. Fails for actions that must always
+    /// be bound.
+    pub fn unbind(&mut self, action: RtsCameraAction) -> Result<(), RebindError> {
+        match action {
+            RtsCameraAction::MouseRotate
+            | RtsCameraAction::SaveBookmark
+            | RtsCameraAction::Bookmark(_) => Err(RebindError::Required),
+            RtsCameraAction::MouseDrag => {
+                self.button_drag = None;
+                Ok(())
+            }
+            _ => {
+                if let Some(keys) = self.keys_mut(action) {
+                    keys.clear();
+                } else if let Some(key_option) = self.key_option_mut(action) {
+                    *key_option = None;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the list of keys for actions that can have several.
+    fn keys_mut(&mut self, action: RtsCameraAction) -> Option<&mut Vec<KeyCode>> {
+        match action {
+            RtsCameraAction::PanUp => Some(&mut self.keys_up),
+            RtsCameraAction::PanDown => Some(&mut self.keys_down),
+            RtsCameraAction::PanLeft => Some(&mut self.keys_left),
+            RtsCameraAction::PanRight => Some(&mut self.keys_right),
+            RtsCameraAction::FastPan => Some(&mut self.keys_fast_pan),
+            RtsCameraAction::RotateLeft => Some(&mut self.keys_rotate_left),
+            RtsCameraAction::RotateRight => Some(&mut self.keys_rotate_right),
+            RtsCameraAction::RotateStepLeft => Some(&mut self.keys_rotate_step_left),
+            RtsCameraAction::RotateStepRight => Some(&mut self.keys_rotate_step_right),
+            RtsCameraAction::TiltUp => Some(&mut self.keys_tilt_up),
+            RtsCameraAction::TiltDown => Some(&mut self.keys_tilt_down),
+            RtsCameraAction::ScrollTilt => Some(&mut self.modifiers_scroll_tilt),
+            _ => None,
+        }
+    }
+
+    /// Returns the key for actions that have at most one.
+    fn key_option_mut(&mut self, action: RtsCameraAction) -> Option<&mut Option<KeyCode>> {
+        match action {
+            RtsCameraAction::ToggleDragMode => Some(&mut self.key_toggle_drag_mode),
+            RtsCameraAction::JumpToHotspot => Some(&mut self.key_jump_to_hotspot),
+            RtsCameraAction::ResetOrientation => Some(&mut self.key_reset_orientation),
+            RtsCameraAction::ZoomIn => Some(&mut self.key_zoom_in),
+            RtsCameraAction::ZoomOut => Some(&mut self.key_zoom_out),
+            RtsCameraAction::ZoomReset => Some(&mut self.key_zoom_reset),
+            _ => None,
+        }
+    }
+}
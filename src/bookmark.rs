@@ -0,0 +1,49 @@
+use crate::RtsCameraSaveState;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Resource that stores camera positions in numbered slots, which can be saved and recalled with
+/// `RtsCameraControls.keys_bookmark`, or directly through this resource. Serializable with the
+/// `serde` feature, so the bookmarks can be kept in save games.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{CameraBookmarks, RtsCamera};
+/// fn bookmark_base(cam_q: Query<&RtsCamera>, mut bookmarks: ResMut<CameraBookmarks>) {
+///     if let Ok(cam) = cam_q.get_single() {
+///         bookmarks.save(0, cam.save_state());
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraBookmarks {
+    slots: HashMap<usize, RtsCameraSaveState>,
+}
+
+impl CameraBookmarks {
+    /// Saves `state` to `slot`, replacing whatever was saved there before.
+    pub fn save(&mut self, slot: usize, state: RtsCameraSaveState) {
+        self.slots.insert(slot, state);
+    }
+
+    /// Returns the state saved in `slot`, if any.
+    pub fn get(&self, slot: usize) -> Option<&RtsCameraSaveState> {
+        self.slots.get(&slot)
+    }
+
+    /// Removes and returns the state saved in `slot`, if any.
+    pub fn remove(&mut self, slot: usize) -> Option<RtsCameraSaveState> {
+        self.slots.remove(&slot)
+    }
+
+    /// Returns all the saved slots and their states, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &RtsCameraSaveState)> {
+        self.slots.iter().map(|(slot, state)| (*slot, state))
+    }
+
+    /// Removes all bookmarks.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+    }
+}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use bevy::math::bounding::{Aabb2d, BoundingCircle};
+use bevy::prelude::*;
+
+/// The area in which an `RtsCamera` is constrained, along the XZ plane of `target_focus`. Imagine
+/// looking directly down relative to `target_focus` and the XZ plane corresponds XY of the Vec2s,
+/// except +Y is up/forward (-Z). With `RtsCamera.ground_plane` set to `InfinitePlane3d::new(Vec3::Z)`,
+/// the Vec2s are world XY.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{CameraBounds, RtsCamera};
+/// // A diamond-shaped (isometric) map
+/// let cam = RtsCamera {
+///     bounds: CameraBounds::convex_polygon([
+///         Vec2::new(0.0, 20.0),
+///         Vec2::new(20.0, 0.0),
+///         Vec2::new(0.0, -20.0),
+///         Vec2::new(-20.0, 0.0),
+///     ]),
+///     ..default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraBounds {
+    /// An axis-aligned rectangle.
+    Aabb(#[cfg_attr(feature = "serde", serde(with = "Aabb2dDef"))] Aabb2d),
+    /// A circle.
+    Circle(#[cfg_attr(feature = "serde", serde(with = "BoundingCircleDef"))] BoundingCircle),
+    /// A convex polygon, with points in either clockwise or counter-clockwise order. If the
+    /// polygon isn't convex, the camera may be able to leave it. An empty polygon doesn't
+    /// constrain the camera at all.
+    ConvexPolygon(Arc<[Vec2]>),
+}
+
+// Bevy's bounding volumes don't implement serde's traits, so describe them here instead
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Aabb2d")]
+struct Aabb2dDef {
+    min: Vec2,
+    max: Vec2,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "BoundingCircle")]
+struct BoundingCircleDef {
+    center: Vec2,
+    circle: Circle,
+}
+
+impl Default for CameraBounds {
+    fn default() -> Self {
+        Self::Aabb(Aabb2d::new(Vec2::ZERO, Vec2::new(20.0, 20.0)))
+    }
+}
+
+impl From<Aabb2d> for CameraBounds {
+    fn from(aabb: Aabb2d) -> Self {
+        Self::Aabb(aabb)
+    }
+}
+
+impl From<BoundingCircle> for CameraBounds {
+    fn from(circle: BoundingCircle) -> Self {
+        Self::Circle(circle)
+    }
+}
+
+impl CameraBounds {
+    /// Creates a `CameraBounds::ConvexPolygon` from the given points, which must be in either
+    /// clockwise or counter-clockwise order.
+    pub fn convex_polygon(points: impl IntoIterator<Item = Vec2>) -> Self {
+        Self::ConvexPolygon(points.into_iter().collect())
+    }
+
+    /// Returns whether `point` is within the bounds.
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.closest_point(point) == point
+    }
+
+    /// Returns the point within the bounds that is closest to `point`. If `point` is already
+    /// within the bounds, it is returned unchanged.
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        match self {
+            Self::Aabb(aabb) => aabb.closest_point(point),
+            Self::Circle(circle) => circle.closest_point(point),
+            Self::ConvexPolygon(points) => polygon_closest_point(points, point),
+        }
+    }
+
+    /// Returns an offset (found iteratively) that moves all `points` within the bounds. If they
+    /// can't all fit, the first point takes priority and is always moved within the
+    /// bounds.
+    pub(crate) fn fit_points(&self, points: &[Vec2]) -> Vec2 {
+        let Some(first) = points.first() else {
+            return Vec2::ZERO;
+        };
+        let mut offset = Vec2::ZERO;
+        for _ in 0..FIT_ITERATIONS {
+            let mut moved = false;
+            for point in points {
+                let point = *point + offset;
+                let delta = self.closest_point(point) - point;
+                if delta != Vec2::ZERO {
+                    offset += delta;
+                    moved = true;
+                }
+            }
+            if !moved {
+                return offset;
+            }
+        }
+        let first = *first + offset;
+        offset + self.closest_point(first) - first
+    }
+}
+
+/// How many times `CameraBounds::fit_points` pushes the points back within the bounds before
+/// giving up.
+const FIT_ITERATIONS: usize = 8;
+
+/// Which part of an `RtsCamera` is kept within its `bounds`. See `RtsCamera.bounds_mode`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraBoundsMode {
+    /// Only the focus is kept within the bounds. The camera itself, and what it can see, may go
+    /// past them, depending on its rotation.
+    #[default]
+    Focus,
+    /// Both the focus and the point on the ground directly below the camera are kept within the
+    /// bounds, so the bounds apply to the area the camera is looking over regardless of its
+    /// rotation or zoom. If the bounds are too small to fit both, the focus takes priority.
+    View,
+    /// The focus and everything the camera can see (the footprint of its view frustum on the
+    /// focus's ground plane) are kept within the bounds, so players can never see past the edge
+    /// of the map. Takes the camera's height, angle, and `Projection` into account. Parts of the
+    /// view above the horizon are limited to the projection's far plane. If the bounds are too
+    /// small to fit the whole view, the focus takes priority.
+    Frustum,
+}
+
+fn polygon_closest_point(points: &[Vec2], point: Vec2) -> Vec2 {
+    match points {
+        [] => return point,
+        [only] => return *only,
+        _ => {}
+    }
+    let edges = || points.iter().zip(points.iter().cycle().skip(1));
+
+    // The point is inside if it's on the same side of every edge, whichever way they wind
+    let (mut any_left, mut any_right) = (false, false);
+    for (a, b) in edges() {
+        let side = (*b - *a).perp_dot(point - *a);
+        any_left |= side > 0.0;
+        any_right |= side < 0.0;
+    }
+    if !(any_left && any_right) {
+        return point;
+    }
+
+    let mut closest = points[0];
+    let mut closest_distance = f32::INFINITY;
+    for (a, b) in edges() {
+        let edge = *b - *a;
+        let t = (point - *a).dot(edge) / edge.length_squared();
+        let candidate = if t.is_finite() {
+            *a + edge * t.clamp(0.0, 1.0)
+        } else {
+            *a
+        };
+        let distance = candidate.distance_squared(point);
+        if distance < closest_distance {
+            closest = candidate;
+            closest_distance = distance;
+        }
+    }
+    closest
+}
@@ -1,23 +1,91 @@
-#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
 
-use crate::{Ground, RtsCamera, RtsCameraSystemSet};
+use crate::director::DirectedCamera;
+use crate::ext::grab_pan_offset;
+use crate::input::handle_input_events;
+use crate::{
+    CameraBookmarks, CameraPath, GroundBackend, RtsCamera, RtsCameraFollow, RtsCameraGround,
+    RtsCameraHotspots, RtsCameraJumpTo, RtsCameraRayFilter, RtsCameraSystemSet,
+};
+use bevy::ecs::entity::EntityHashMap;
 use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
 use bevy::input::ButtonInput;
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
 use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
 use bevy::window::{CursorGrabMode, PrimaryWindow};
-use std::f32::consts::PI;
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::time::Duration;
 
-pub struct RtsCameraControlsPlugin;
+/// Filters out cameras that the player can't control at the moment, because they're playing a
+/// `CameraPath` or being directed by `RtsCameraDirector`.
+type PlayerControlled = (Without<CameraPath>, Without<DirectedCamera>);
 
-impl Plugin for RtsCameraControlsPlugin {
+pub struct RtsCameraControlsPlugin<B: GroundBackend>(PhantomData<B>);
+
+impl<B: GroundBackend> Default for RtsCameraControlsPlugin<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: GroundBackend> Plugin for RtsCameraControlsPlugin<B> {
     fn build(&self, app: &mut App) {
+        app.init_resource::<RtsCameraInputEnabled>()
+            .init_resource::<RtsCameraPointerBlocked>()
+            .add_systems(First, (reset_pointer_blocked, tick_input_suppression));
+        #[cfg(feature = "bevy_ui")]
+        app.add_systems(
+            PreUpdate,
+            block_pointer_over_ui.after(bevy::ui::UiSystem::Focus),
+        );
+        #[cfg(feature = "egui")]
+        app.add_systems(
+            PreUpdate,
+            block_pointer_over_egui.after(bevy_egui::EguiPreUpdateSet::BeginPass),
+        );
         app.add_systems(
             Update,
-            (zoom, pan, grab_pan, rotate).before(RtsCameraSystemSet),
+            (
+                // Always runs so it can release the cursor when input is disabled
+                confine_cursor,
+                toggle_drag_mode.run_if(rts_camera_input_enabled),
+                (
+                    (zoom::<B>, pan, face_direction, jump_to_hotspot, bookmark)
+                        .run_if(rts_camera_input_enabled),
+                    // These always run so they can release the cursor if input is disabled
+                    // mid-drag, and check `RtsCameraInputEnabled` themselves
+                    grab_pan::<B>,
+                    rotate::<B>,
+                ),
+                touch.run_if(rts_camera_input_enabled),
+            )
+                .chain()
+                .run_if(input_available)
+                .before(handle_input_events)
+                .before(RtsCameraSystemSet),
         );
     }
 }
 
+/// Returns whether the input the controller reads exists, which it doesn't when running headless
+/// without `InputPlugin`.
+fn input_available(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    mouse_wheel: Option<Res<Events<MouseWheel>>>,
+    mouse_motion: Option<Res<Events<MouseMotion>>>,
+    touches: Option<Res<Touches>>,
+) -> bool {
+    keys.is_some()
+        && mouse.is_some()
+        && mouse_wheel.is_some()
+        && mouse_motion.is_some()
+        && touches.is_some()
+}
+
 /// Optional camera controller. If you want to use an input manager, don't use this and instead
 /// control the camera yourself by updating `RtsCamera.target_focus` and `RtsCamera.target_zoom`.
 /// # Example
@@ -27,7 +95,7 @@ impl Plugin for RtsCameraControlsPlugin {
 /// # fn main() {
 /// #     App::new()
 /// #         .add_plugins(DefaultPlugins)
-/// #         .add_plugins(RtsCameraPlugin)
+/// #         .add_plugins(RtsCameraPlugin::default())
 /// #         .add_systems(Startup, setup)
 /// #         .run();
 /// # }
@@ -40,50 +108,266 @@ impl Plugin for RtsCameraControlsPlugin {
 ///  }
 /// ```
 #[derive(Component, Debug, PartialEq, Clone)]
+#[require(RtsCameraInputSuppression)]
 pub struct RtsCameraControls {
-    /// The key that will pan the camera up (or forward).
-    /// Defaults to `KeyCode::ArrowUp`.
-    pub key_up: KeyCode,
-    /// The key that will pan the camera down (or backward).
-    /// Defaults to `KeyCode::ArrowDown`.
-    pub key_down: KeyCode,
-    /// The key that will pan the camera left.
-    /// Defaults to `KeyCode::ArrowLeft`.
-    pub key_left: KeyCode,
-    /// The key that will pan the camera right.
-    /// Defaults to `KeyCode::ArrowRight`.
-    pub key_right: KeyCode,
+    /// The keys that will pan the camera up (or forward).
+    /// Defaults to `[KeyCode::ArrowUp]`.
+    pub keys_up: Vec<KeyCode>,
+    /// The keys that will pan the camera down (or backward).
+    /// Defaults to `[KeyCode::ArrowDown]`.
+    pub keys_down: Vec<KeyCode>,
+    /// The keys that will pan the camera left.
+    /// Defaults to `[KeyCode::ArrowLeft]`.
+    pub keys_left: Vec<KeyCode>,
+    /// The keys that will pan the camera right.
+    /// Defaults to `[KeyCode::ArrowRight]`.
+    pub keys_right: Vec<KeyCode>,
     /// The mouse button used to rotate the camera.
     /// Defaults to `MouseButton::Middle`.
     pub button_rotate: MouseButton,
-    /// The key that will rotate the camera left.
-    /// Defaults to `KeyCode::KeyQ`.
-    pub key_rotate_left: KeyCode,
-    /// The key that will rotate the camera right.
-    /// Defaults to `KeyCode::KeyE`.
-    pub key_rotate_right: KeyCode,
-    /// How fast the keys will rotate the camera.
-    /// Defaults to `16.0`.
+    /// Keys, one of which must be held when pressing `button_rotate` for it to rotate the camera,
+    /// e.g. `vec![KeyCode::AltLeft, KeyCode::AltRight]` so that `MouseButton::Left` can still be
+    /// used for selecting units. Once rotating, the key can be let go. Leave empty to rotate
+    /// without a modifier. Doesn't affect `keys_rotate_left` and `keys_rotate_right`.
+    /// Defaults to no keys.
+    pub modifiers_rotate: Vec<KeyCode>,
+    /// The keys that will rotate the camera left.
+    /// Defaults to `[KeyCode::KeyQ]`.
+    pub keys_rotate_left: Vec<KeyCode>,
+    /// The keys that will rotate the camera right.
+    /// Defaults to `[KeyCode::KeyE]`.
+    pub keys_rotate_right: Vec<KeyCode>,
+    /// How fast the keys will rotate the camera, in radians per second.
+    /// Defaults to `1.5`.
     pub key_rotate_speed: f32,
+    /// How much moving the mouse while holding `button_rotate` rotates the camera. At `1.0`, moving
+    /// the mouse across the width of the window makes a half turn.
+    /// Defaults to `1.0`.
+    pub rotate_sensitivity: f32,
+    /// Whether to reverse the direction the camera rotates when moving the mouse while holding
+    /// `button_rotate`.
+    /// Defaults to `false`.
+    pub invert_rotate: bool,
     /// Whether to lock the mouse cursor in place while rotating.
     /// Defaults to `false`.
     pub lock_on_rotate: bool,
+    /// Whether rotating with `button_rotate` orbits around the point on the ground under the
+    /// cursor when the button was pressed, instead of around the focus, like in many city
+    /// builders. The focus moves around that point as the camera rotates. Falls back to rotating
+    /// around the focus if there's no ground under the cursor.
+    /// Defaults to `false`.
+    pub rotate_around_cursor: bool,
+    /// Whether moving the mouse up and down while holding `button_rotate` tilts the camera (see
+    /// `RtsCamera::tilt`), for an orbit-style camera that rotates and tilts with one button. The
+    /// tilt is limited by `RtsCamera.min_angle` and `RtsCamera.max_angle`. Disable to only rotate.
+    /// Defaults to `false`.
+    pub tilt_on_rotate: bool,
+    /// How much moving the mouse tilts the camera when `tilt_on_rotate` is enabled, relative to
+    /// how much it rotates. Use a negative value to invert the direction.
+    /// Defaults to `1.0`.
+    pub mouse_tilt_sensitivity: f32,
+    /// The keys that will tilt the camera up towards the horizon.
+    /// Defaults to no keys.
+    pub keys_tilt_up: Vec<KeyCode>,
+    /// The keys that will tilt the camera down.
+    /// Defaults to no keys.
+    pub keys_tilt_down: Vec<KeyCode>,
+    /// How fast the keys will tilt the camera, in radians per second.
+    /// Defaults to `1.0`.
+    pub key_tilt_speed: f32,
+    /// Keys that will smoothly rotate the camera to face exactly north (-Z), east (+X), south
+    /// (+Z), and west (-X), in that order.
+    /// Defaults to `None`.
+    pub keys_face_cardinal: Option<[KeyCode; 4]>,
+    /// Keys that will smoothly rotate the camera to face exactly north-east, south-east,
+    /// south-west, and north-west, in that order. Useful for isometric-style views.
+    /// Defaults to `None`.
+    pub keys_face_diagonal: Option<[KeyCode; 4]>,
+    /// The angle (in radians) between the directions the camera can snap to, starting from north,
+    /// e.g. `FRAC_PI_2` for the four cardinal directions, or `FRAC_PI_4` to include the diagonals.
+    /// Used by `keys_rotate_step_left`, `keys_rotate_step_right`, and `rotation_soft_snap`. Useful
+    /// for grid-based games that want axis-aligned views.
+    /// Defaults to `None`.
+    pub rotation_snap: Option<f32>,
+    /// The keys that will smoothly rotate the camera left to the next `rotation_snap` direction.
+    /// Defaults to no keys.
+    pub keys_rotate_step_left: Vec<KeyCode>,
+    /// The keys that will smoothly rotate the camera right to the next `rotation_snap` direction.
+    /// Defaults to no keys.
+    pub keys_rotate_step_right: Vec<KeyCode>,
+    /// When rotating with `button_rotate` ends within this angle (in radians) of a `rotation_snap`
+    /// direction, the camera finishes rotating to face it.
+    /// Defaults to `None`.
+    pub rotation_soft_snap: Option<f32>,
+    /// The key that smoothly returns the camera to its `RtsCamera.reset_view` (see
+    /// `RtsCamera::reset_orientation`).
+    /// Defaults to `None`.
+    pub key_reset_orientation: Option<KeyCode>,
+    /// The key that moves the camera to the next `CameraHotspot` (see `RtsCameraHotspots`), e.g.
+    /// `KeyCode::Space`. Pressing it repeatedly cycles through recent hotspots.
+    /// Defaults to `None`.
+    pub key_jump_to_hotspot: Option<KeyCode>,
+    /// Keys that recall the camera position saved in `CameraBookmarks`, where the first key is slot
+    /// `0`, the second is slot `1`, and so on, e.g. `vec![KeyCode::F5, KeyCode::F6, KeyCode::F7,
+    /// KeyCode::F8]`. Holding `key_save_bookmark` while pressing one saves the current position to
+    /// that slot instead.
+    /// Defaults to no keys.
+    pub keys_bookmark: Vec<KeyCode>,
+    /// The key to hold while pressing one of `keys_bookmark` to save a bookmark.
+    /// Defaults to `KeyCode::ControlLeft`.
+    pub key_save_bookmark: KeyCode,
     /// The mouse button used to 'drag pan' the camera.
     /// Defaults to `None`.
     pub button_drag: Option<MouseButton>,
+    /// Keys, one of which must be held when pressing `button_drag` for it to drag pan the camera.
+    /// Once dragging, the key can be let go. Leave empty to drag without a modifier.
+    /// Defaults to no keys.
+    pub modifiers_drag: Vec<KeyCode>,
+    /// How far the camera moves when drag panning, relative to the mouse. At `1.0`, the ground
+    /// stays under the cursor.
+    /// Defaults to `1.0`.
+    pub drag_sensitivity: f32,
+    /// Whether to reverse the direction of drag panning along the X axis (left and right).
+    /// Defaults to `false`.
+    pub invert_drag_x: bool,
+    /// Whether to reverse the direction of drag panning along the Y axis (forward and backward).
+    /// Defaults to `false`.
+    pub invert_drag_y: bool,
     /// Whether to lock the mouse cursor in place while dragging.
     /// Defaults to `false`.
     pub lock_on_drag: bool,
-    /// How far away from the side of the screen edge pan will kick in, defined as a percentage
-    /// of the window's height. Set to `0.0` to disable edge panning.
-    /// Defaults to `0.05` (5%).
-    pub edge_pan_width: f32,
+    /// Whether to keep the mouse cursor within the window (see `CursorGrabMode::Confined`) while
+    /// these controls are enabled and the window has focus, so it can't escape past the edge pan
+    /// area in windowed mode or onto another monitor. The cursor is released when the controls
+    /// or `RtsCameraInputEnabled` are disabled, or the window loses focus.
+    /// Defaults to `false`.
+    pub confine_cursor: bool,
+    /// Whether the camera keeps moving when a drag pan is released while the mouse is still
+    /// moving, and then glides to a stop (slowed down by `grab_fling_friction`), like a map app.
+    /// Defaults to `false`.
+    pub grab_fling: bool,
+    /// How quickly a `grab_fling` slows down. Each second, the camera's speed is reduced by a
+    /// factor of `e^grab_fling_friction`, so higher values stop sooner.
+    /// Defaults to `4.0`.
+    pub grab_fling_friction: f32,
+    /// The key that swaps `button_rotate` and `button_drag` (see `toggle_drag_mode`).
+    /// Defaults to `None`.
+    pub key_toggle_drag_mode: Option<KeyCode>,
+    /// How far away from each side of the screen edge pan will kick in, defined as a percentage
+    /// of the window's (or viewport's, see `edge_pan_restrict_to_viewport`) height. Set an edge
+    /// to `0.0` to disable edge panning on that side, e.g. the bottom edge if the HUD lives there,
+    /// or use `EdgePanWidths::all(0.0)` to disable edge panning entirely.
+    /// Defaults to `EdgePanWidths::all(0.05)` (5%).
+    pub edge_pan_width: EdgePanWidths,
+    /// Areas of the window, in logical pixels from the top left, where edge pan doesn't kick in,
+    /// e.g. over a minimap or command card in a corner of the screen.
+    /// Defaults to no areas.
+    pub edge_pan_exclusions: Vec<Rect>,
+    /// How edge pan speeds up as the cursor moves from the inner side of the edge pan area
+    /// (no movement) to the edge of the screen (full `pan_speed`). Set to `None` to always pan at
+    /// full speed as soon as the cursor is within the edge pan area.
+    /// Defaults to `Some(EaseFunction::Linear)`.
+    pub edge_pan_ramp: Option<EaseFunction>,
+    /// Whether edge pan only happens while the window has focus, so the camera doesn't drift away
+    /// while the player is using another window.
+    /// Defaults to `true`.
+    pub edge_pan_requires_focus: bool,
+    /// Whether edge pan carries on when the cursor leaves the window, as if the cursor were still
+    /// where it left. In windowed mode or with multiple monitors, the cursor can move past the
+    /// edge of the window, which otherwise stops edge panning.
+    /// Defaults to `false`.
+    pub edge_pan_when_cursor_outside: bool,
+    /// How keyboard and edge panning in two directions at once (e.g. up and left, or with the
+    /// cursor in a corner of the screen) is combined.
+    /// Defaults to `DiagonalPan::Normalize`.
+    pub diagonal_pan: DiagonalPan,
+    /// Whether edge pan should use the edges of the camera's viewport (see `Camera.viewport`)
+    /// instead of the edges of the window. Enable this for split-screen or editor-style layouts
+    /// where the camera only covers part of the window.
+    /// Defaults to `false`.
+    pub edge_pan_restrict_to_viewport: bool,
     /// Speed of camera pan (either via keyboard controls or edge panning).
     /// Defaults to `15.0`.
     pub pan_speed: f32,
+    /// Keys that speed up keyboard and edge panning by `fast_pan_multiplier` while held, e.g.
+    /// `vec![KeyCode::ShiftLeft, KeyCode::ShiftRight]`.
+    /// Defaults to no keys.
+    pub keys_fast_pan: Vec<KeyCode>,
+    /// How much faster panning is while one of `keys_fast_pan` is held.
+    /// Defaults to `2.0`.
+    pub fast_pan_multiplier: f32,
+    /// How much `pan_speed` is scaled by depending on zoom, so panning (roughly) feels the same
+    /// speed at different zoom levels. The start of the range is the factor when fully zoomed out
+    /// and the end is the factor when fully zoomed in, with zoom levels in between interpolated
+    /// linearly. Also applies to `RtsCameraInputKind::Pan`. Large maps may want a bigger range so
+    /// panning while zoomed out crosses the map quickly, e.g. `2.0..=0.5`.
+    /// Defaults to `1.0..=0.5`.
+    pub pan_speed_zoom_factor: RangeInclusive<f32>,
+    /// How long in seconds keyboard and edge panning takes to ramp up to full speed. Set to `0.0`
+    /// to start panning at full speed immediately.
+    /// Defaults to `0.0`.
+    pub pan_acceleration_time: f32,
+    /// How long in seconds keyboard and edge panning takes to glide to a stop from full speed
+    /// once the input is released. Set to `0.0` to stop immediately.
+    /// Defaults to `0.0`.
+    pub pan_deceleration_time: f32,
     /// How much the camera will zoom.
+    /// Only used when `zoom_mode` is `ZoomMode::Normalized`.
     /// Defaults to `1.0`.
     pub zoom_sensitivity: f32,
+    /// Whether to reverse the direction of zooming, so scrolling up zooms out.
+    /// Defaults to `false`.
+    pub invert_zoom: bool,
+    /// How many pixels of pixel-unit scrolling (as sent by most trackpads) count as one line of a
+    /// mouse wheel. Lower values make trackpad zooming faster. Trackpads report very different
+    /// amounts depending on the OS, so games may want to expose this as a setting.
+    /// Defaults to `1000.0`.
+    pub scroll_pixels_per_line: f32,
+    /// Whether zooming with pixel-unit scrolling (as sent by most trackpads) carries on for a
+    /// moment after the gesture ends, slowing down by `kinetic_zoom_friction`. Useful on
+    /// platforms that don't already send momentum scrolling themselves.
+    /// Defaults to `false`.
+    pub kinetic_zoom: bool,
+    /// How quickly a `kinetic_zoom` slows down. Each second, the zoom speed is reduced by a factor
+    /// of `e^kinetic_zoom_friction`, so higher values stop sooner.
+    /// Defaults to `6.0`.
+    pub kinetic_zoom_friction: f32,
+    /// How zoom input is converted into a change of `RtsCamera.target_zoom`.
+    /// Defaults to `ZoomMode::Normalized`.
+    pub zoom_mode: ZoomMode,
+    /// Whether scrolling zooms towards (or away from) the point on the ground under the cursor
+    /// instead of the center of the screen. Has no effect on orthographic cameras using
+    /// `OrthographicZoom::Height`, as their view doesn't change size when zooming.
+    /// Defaults to `false`.
+    pub zoom_to_cursor: bool,
+    /// The key that zooms in, for players without a mouse wheel. Each press zooms as much as one
+    /// line of the mouse wheel, and holding it keeps zooming at `key_zoom_repeat_rate`.
+    /// Defaults to `None`.
+    pub key_zoom_in: Option<KeyCode>,
+    /// The key that zooms out. See `key_zoom_in`.
+    /// Defaults to `None`.
+    pub key_zoom_out: Option<KeyCode>,
+    /// How many times per second holding `key_zoom_in` or `key_zoom_out` repeats, after a short
+    /// delay.
+    /// Defaults to `10.0`.
+    pub key_zoom_repeat_rate: f32,
+    /// The key that smoothly zooms to `zoom_reset_level`, e.g. to see as much of the map as
+    /// possible.
+    /// Defaults to `None`.
+    pub key_zoom_reset: Option<KeyCode>,
+    /// The zoom that `key_zoom_reset` zooms to.
+    /// Defaults to `0.0` (fully zoomed out).
+    pub zoom_reset_level: f32,
+    /// Keys, one of which makes scrolling tilt the camera (see `RtsCamera::tilt`) instead of
+    /// zooming while it's held, like in Cities: Skylines. Scrolling up tilts the camera up towards
+    /// the horizon, and the tilt is limited by `RtsCamera.min_angle` and `RtsCamera.max_angle`.
+    /// Leave empty to always zoom.
+    /// Defaults to no keys.
+    pub modifiers_scroll_tilt: Vec<KeyCode>,
+    /// How much one line of the mouse wheel tilts the camera while holding one of
+    /// `modifiers_scroll_tilt`, in radians. Use a negative value to invert the direction.
+    /// Defaults to `0.05`.
+    pub scroll_tilt_sensitivity: f32,
     /// Whether these controls are enabled.
     /// Defaults to `true`.
     pub enabled: bool,
@@ -92,241 +376,1510 @@ pub struct RtsCameraControls {
 impl Default for RtsCameraControls {
     fn default() -> Self {
         RtsCameraControls {
-            key_up: KeyCode::ArrowUp,
-            key_down: KeyCode::ArrowDown,
-            key_left: KeyCode::ArrowLeft,
-            key_right: KeyCode::ArrowRight,
+            keys_up: vec![KeyCode::ArrowUp],
+            keys_down: vec![KeyCode::ArrowDown],
+            keys_left: vec![KeyCode::ArrowLeft],
+            keys_right: vec![KeyCode::ArrowRight],
             button_rotate: MouseButton::Middle,
-            key_rotate_left: KeyCode::KeyQ,
-            key_rotate_right: KeyCode::KeyE,
-            key_rotate_speed: 16.0,
+            modifiers_rotate: Vec::new(),
+            keys_rotate_left: vec![KeyCode::KeyQ],
+            keys_rotate_right: vec![KeyCode::KeyE],
+            key_rotate_speed: 1.5,
+            rotate_sensitivity: 1.0,
+            invert_rotate: false,
             lock_on_rotate: false,
+            rotate_around_cursor: false,
+            tilt_on_rotate: false,
+            mouse_tilt_sensitivity: 1.0,
+            keys_tilt_up: Vec::new(),
+            keys_tilt_down: Vec::new(),
+            key_tilt_speed: 1.0,
+            keys_face_cardinal: None,
+            keys_face_diagonal: None,
+            rotation_snap: None,
+            keys_rotate_step_left: Vec::new(),
+            keys_rotate_step_right: Vec::new(),
+            rotation_soft_snap: None,
+            key_reset_orientation: None,
+            key_jump_to_hotspot: None,
+            keys_bookmark: Vec::new(),
+            key_save_bookmark: KeyCode::ControlLeft,
             button_drag: None,
+            modifiers_drag: Vec::new(),
+            drag_sensitivity: 1.0,
+            invert_drag_x: false,
+            invert_drag_y: false,
             lock_on_drag: false,
-            edge_pan_width: 0.05,
+            confine_cursor: false,
+            grab_fling: false,
+            grab_fling_friction: 4.0,
+            key_toggle_drag_mode: None,
+            edge_pan_width: EdgePanWidths::all(0.05),
+            edge_pan_exclusions: Vec::new(),
+            edge_pan_ramp: Some(EaseFunction::Linear),
+            edge_pan_requires_focus: true,
+            edge_pan_when_cursor_outside: false,
+            diagonal_pan: DiagonalPan::Normalize,
+            edge_pan_restrict_to_viewport: false,
             pan_speed: 15.0,
+            keys_fast_pan: Vec::new(),
+            fast_pan_multiplier: 2.0,
+            pan_speed_zoom_factor: 1.0..=0.5,
+            pan_acceleration_time: 0.0,
+            pan_deceleration_time: 0.0,
             zoom_sensitivity: 1.0,
+            invert_zoom: false,
+            scroll_pixels_per_line: 1000.0,
+            kinetic_zoom: false,
+            kinetic_zoom_friction: 6.0,
+            zoom_mode: ZoomMode::Normalized,
+            zoom_to_cursor: false,
+            key_zoom_in: None,
+            key_zoom_out: None,
+            key_zoom_repeat_rate: 10.0,
+            key_zoom_reset: None,
+            zoom_reset_level: 0.0,
+            modifiers_scroll_tilt: Vec::new(),
+            scroll_tilt_sensitivity: 0.05,
             enabled: true,
         }
     }
 }
 
-pub fn zoom(
+impl RtsCameraControls {
+    /// Returns how much panning is scaled by at the given zoom level. See `pan_speed_zoom_factor`.
+    pub fn pan_speed_factor(&self, zoom: f32) -> f32 {
+        self.pan_speed_zoom_factor
+            .start()
+            .lerp(*self.pan_speed_zoom_factor.end(), zoom.clamp(0.0, 1.0))
+    }
+
+    /// Applies `invert_zoom` to zoom input (e.g. scroll lines).
+    pub fn zoom_input(&self, zoom: f32) -> f32 {
+        if self.invert_zoom {
+            -zoom
+        } else {
+            zoom
+        }
+    }
+
+    /// Applies `rotate_sensitivity` and `invert_rotate` to a rotation in radians.
+    pub fn rotate_input(&self, angle: f32) -> f32 {
+        let angle = angle * self.rotate_sensitivity;
+        if self.invert_rotate {
+            -angle
+        } else {
+            angle
+        }
+    }
+
+    /// Applies `drag_sensitivity`, `invert_drag_x` and `invert_drag_y` to a drag of the mouse in
+    /// logical pixels.
+    pub fn drag_input(&self, delta: Vec2) -> Vec2 {
+        let invert = Vec2::new(
+            if self.invert_drag_x { -1.0 } else { 1.0 },
+            if self.invert_drag_y { -1.0 } else { 1.0 },
+        );
+        delta * invert * self.drag_sensitivity
+    }
+
+    /// Swaps `button_rotate` and `button_drag`, so that the button that rotated the camera now
+    /// drag pans it and vice versa. Does nothing if `button_drag` is `None`. If either button is
+    /// held down when this is called, that rotation or drag is ended and the cursor is unlocked.
+    pub fn toggle_drag_mode(&mut self) {
+        if let Some(button_drag) = self.button_drag {
+            self.button_drag = Some(self.button_rotate);
+            self.button_rotate = button_drag;
+        }
+    }
+}
+
+pub fn toggle_drag_mode(
+    mut controls_q: Query<&mut RtsCameraControls>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    for mut controller in controls_q.iter_mut().filter(|ctrl| ctrl.enabled) {
+        if controller
+            .key_toggle_drag_mode
+            .is_some_and(|key| keys.just_pressed(key))
+        {
+            controller.toggle_drag_mode();
+        }
+    }
+}
+
+pub fn confine_cursor(
+    controls_q: Query<(&RtsCameraControls, &Camera)>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    mut window_q: Query<(Entity, &mut Window)>,
+    input_enabled: Res<RtsCameraInputEnabled>,
+    // The grab mode of each window that's been confined, from before it was confined
+    mut confined: Local<EntityHashMap<CursorGrabMode>>,
+) {
+    let primary_window = primary_window_q.get_single().ok();
+    for (window_entity, mut window) in window_q.iter_mut() {
+        let wants_confined = input_enabled.0
+            && window.focused
+            && controls_q.iter().any(|(controller, camera)| {
+                controller.enabled
+                    && controller.confine_cursor
+                    && camera_window(camera, primary_window) == Some(window_entity)
+            });
+        match confined.get(&window_entity).copied() {
+            None if wants_confined && window.cursor_options.grab_mode == CursorGrabMode::None => {
+                confined.insert(window_entity, window.cursor_options.grab_mode);
+                window.cursor_options.grab_mode = CursorGrabMode::Confined;
+            }
+            Some(previous) if !wants_confined => {
+                // Leave the cursor alone if something else has changed it since (e.g. it's locked
+                // while rotating)
+                if window.cursor_options.grab_mode == CursorGrabMode::Confined {
+                    window.cursor_options.grab_mode = previous;
+                }
+                confined.remove(&window_entity);
+            }
+            _ => {}
+        }
+    }
+    confined.retain(|window, _| window_q.contains(*window));
+}
+
+/// Optional touch controller, for touch screens (e.g. tablets or mobile browsers). Can be used
+/// alongside `RtsCameraControls`.
+/// - One finger drag: pan
+/// - Two finger pinch: zoom
+/// - Two finger twist: rotate
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCameraPlugin, RtsCamera, RtsCameraTouchControls};
+/// # fn main() {
+/// #     App::new()
+/// #         .add_plugins(DefaultPlugins)
+/// #         .add_plugins(RtsCameraPlugin::default())
+/// #         .add_systems(Startup, setup)
+/// #         .run();
+/// # }
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn((
+///             RtsCamera::default(),
+///             RtsCameraTouchControls::default(),
+///         ));
+///  }
+/// ```
+#[derive(Component, Debug, PartialEq, Clone)]
+#[require(RtsCameraInputSuppression)]
+pub struct RtsCameraTouchControls {
+    /// How far the camera pans when dragging with one finger. At `1.0`, the ground stays under the
+    /// finger.
+    /// Defaults to `1.0`.
+    pub pan_sensitivity: f32,
+    /// How much the camera zooms when pinching. At `1.0`, pinching across the full height of the
+    /// screen zooms across the full zoom range.
+    /// Defaults to `1.0`.
+    pub zoom_sensitivity: f32,
+    /// Whether twisting two fingers rotates the camera.
+    /// Defaults to `true`.
+    pub rotate: bool,
+    /// Whether these controls are enabled.
+    /// Defaults to `true`.
+    pub enabled: bool,
+}
+
+impl Default for RtsCameraTouchControls {
+    fn default() -> Self {
+        RtsCameraTouchControls {
+            pan_sensitivity: 1.0,
+            zoom_sensitivity: 1.0,
+            rotate: true,
+            enabled: true,
+        }
+    }
+}
+
+pub fn touch(
+    mut cam_q: Query<
+        (
+            &Transform,
+            &mut RtsCamera,
+            &RtsCameraTouchControls,
+            &RtsCameraInputSuppression,
+            &Camera,
+            &Projection,
+        ),
+        PlayerControlled,
+    >,
+    touches: Res<Touches>,
+) {
+    let active_touches = touches.iter().collect::<Vec<_>>();
+    for (cam_tfm, mut cam, controller, suppression, camera, projection) in cam_q
+        .iter_mut()
+        .filter(|(_, _, ctrl, _, _, _)| ctrl.enabled)
+    {
+        match active_touches.as_slice() {
+            [] => {}
+            [_] if suppression.is_suppressed() => {}
+            [touch] => {
+                let delta = touch.delta() * controller.pan_sensitivity;
+                if delta != Vec2::ZERO {
+                    let offset = grab_pan_offset(cam_tfm, &cam, camera, projection, None, delta);
+                    cam.target_focus.translation += offset;
+                }
+            }
+            [first, second, ..] => {
+                let previous = first.previous_position() - second.previous_position();
+                let current = first.position() - second.position();
+
+                if let Some(vp_size) = camera.logical_viewport_size() {
+                    let pinch = (current.length() - previous.length()) / vp_size.y;
+                    if pinch != 0.0 {
+                        cam.target_zoom =
+                            (cam.target_zoom + pinch * controller.zoom_sensitivity).clamp(0.0, 1.0);
+                    }
+                }
+
+                // Screen space Y points down, so a positive angle is a clockwise twist, which
+                // should turn the world clockwise (i.e. turn the camera counterclockwise)
+                if controller.rotate && previous != Vec2::ZERO && current != Vec2::ZERO {
+                    let twist = previous.angle_to(current);
+                    if twist != 0.0 {
+                        cam.target_focus.rotate_local_y(twist);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Component that makes the built-in controllers ignore pan input (keyboard, edge, drag, and touch
+/// pan) for a while. Use this after moving the camera from gameplay code (e.g. when clicking the
+/// minimap), so the player doesn't immediately pan away from where it jumped to because the cursor
+/// was still at the edge of the screen. Added automatically with `RtsCameraControls` and
+/// `RtsCameraTouchControls`.
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, RtsCameraInputSuppression};
+/// fn on_minimap_click(mut cam_q: Query<(&mut RtsCamera, &mut RtsCameraInputSuppression)>) {
+///     let (mut cam, mut suppression) = cam_q.single_mut();
+///     cam.target_focus.translation = Vec3::new(50.0, 0.0, 20.0);
+///     suppression.suppress_for(Duration::from_millis(300));
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RtsCameraInputSuppression {
+    remaining: Duration,
+}
+
+impl RtsCameraInputSuppression {
+    /// Ignores pan input for `duration`, and stops any pan in progress. Doesn't shorten an
+    /// existing suppression.
+    pub fn suppress_for(&mut self, duration: Duration) {
+        self.remaining = self.remaining.max(duration);
+    }
+
+    /// Returns how much longer pan input is ignored for.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+
+    /// Returns whether pan input is currently ignored.
+    pub fn is_suppressed(&self) -> bool {
+        self.remaining > Duration::ZERO
+    }
+}
+
+/// Counts down `RtsCameraInputSuppression`. Runs even while input is disabled, so the suppression
+/// doesn't outlast it.
+fn tick_input_suppression(
+    mut suppression_q: Query<&mut RtsCameraInputSuppression>,
+    time: Res<Time<Real>>,
+) {
+    for mut suppression in suppression_q.iter_mut() {
+        if suppression.is_suppressed() {
+            suppression.remaining = suppression.remaining.saturating_sub(time.delta());
+        }
+    }
+}
+
+/// Resource that enables or disables all of the built-in controllers (`RtsCameraControls` and
+/// `RtsCameraTouchControls`) at once, e.g. while a menu or dialog is open. This is equivalent to
+/// setting `enabled` to `false` on every controller, but doesn't require touching them.
+/// `RtsCameraInput` events are still applied while this is `false`.
+/// Defaults to `true`.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::RtsCameraInputEnabled;
+/// fn open_menu(mut input_enabled: ResMut<RtsCameraInputEnabled>) {
+///     input_enabled.0 = false;
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RtsCameraInputEnabled(pub bool);
+
+impl Default for RtsCameraInputEnabled {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Run condition that returns `true` if `RtsCameraInputEnabled` is `true` (or doesn't exist). Used
+/// by the built-in controllers, and can be used by your own camera input systems so they're
+/// suspended at the same time.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{rts_camera_input_enabled, RtsCameraSystemSet};
+/// # fn main() {
+/// App::new().add_systems(
+///     Update,
+///     my_camera_input
+///         .run_if(rts_camera_input_enabled)
+///         .before(RtsCameraSystemSet),
+/// );
+/// # }
+/// # fn my_camera_input() {}
+/// ```
+pub fn rts_camera_input_enabled(input_enabled: Option<Res<RtsCameraInputEnabled>>) -> bool {
+    input_enabled.is_none_or(|enabled| enabled.0)
+}
+
+/// Resource that, when `true`, stops `RtsCameraControls` from reacting to the mouse (zoom, edge
+/// pan, and starting a rotation or drag pan), for example because the cursor is over UI. Keyboard
+/// controls are not affected.
+/// This is reset to `false` at the start of every frame. With the `bevy_ui` feature enabled, it's
+/// set to `true` automatically when the cursor is over a UI node with an `Interaction` or
+/// `RelativeCursorPosition` component. With the `egui` feature enabled, it's also set to `true`
+/// when egui wants the pointer or the cursor is over an egui area. For other UI libraries, set it
+/// to `true` yourself before `RtsCameraSystemSet` when the UI wants the pointer.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::RtsCameraPointerBlocked;
+/// # fn ui_wants_pointer() -> bool { false }
+/// fn block_camera_input(mut blocked: ResMut<RtsCameraPointerBlocked>) {
+///     if ui_wants_pointer() {
+///         blocked.0 = true;
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RtsCameraPointerBlocked(pub bool);
+
+fn reset_pointer_blocked(mut blocked: ResMut<RtsCameraPointerBlocked>) {
+    blocked.0 = false;
+}
+
+#[cfg(feature = "bevy_ui")]
+fn block_pointer_over_ui(
+    interaction_q: Query<&Interaction>,
+    relative_cursor_q: Query<&bevy::ui::RelativeCursorPosition>,
+    mut blocked: ResMut<RtsCameraPointerBlocked>,
+) {
+    if interaction_q.iter().any(|i| *i != Interaction::None)
+        || relative_cursor_q.iter().any(|r| r.mouse_over())
+    {
+        blocked.0 = true;
+    }
+}
+
+#[cfg(feature = "egui")]
+fn block_pointer_over_egui(
+    mut contexts: bevy_egui::EguiContexts,
+    mut blocked: ResMut<RtsCameraPointerBlocked>,
+) {
+    // There's no context until the primary window exists
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+    if ctx.wants_pointer_input() || ctx.is_pointer_over_area() {
+        blocked.0 = true;
+    }
+}
+
+pub fn zoom<B: GroundBackend>(
     mut mouse_wheel: EventReader<MouseWheel>,
-    mut cam_q: Query<(&mut RtsCamera, &RtsCameraControls)>,
+    mut cam_q: Query<
+        (
+            Entity,
+            &mut RtsCamera,
+            &RtsCameraControls,
+            &Camera,
+            &Projection,
+            Option<&RtsCameraRayFilter>,
+        ),
+        PlayerControlled,
+    >,
+    mut pending_height: Local<EntityHashMap<f32>>,
+    mut pending_steps: Local<EntityHashMap<f32>>,
+    mut kinetic: Local<EntityHashMap<KineticZoom>>,
+    mut key_held: Local<EntityHashMap<f32>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+    pointer_blocked: Res<RtsCameraPointerBlocked>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    window_q: Query<&Window>,
+    ground: RtsCameraGround<B>,
 ) {
-    for (mut cam, cam_controls) in cam_q.iter_mut().filter(|(_, ctrl)| ctrl.enabled) {
-        let zoom_amount = mouse_wheel
-            .read()
-            .map(|event| match event.unit {
-                MouseScrollUnit::Line => event.y,
-                MouseScrollUnit::Pixel => event.y * 0.001,
-            })
-            .fold(0.0, |acc, val| acc + val);
-        let new_zoom =
-            (cam.target_zoom + zoom_amount * 0.5 * cam_controls.zoom_sensitivity).clamp(0.0, 1.0);
-        cam.target_zoom = new_zoom;
+    // Lines and pixels are kept apart, as each camera converts pixels to lines itself
+    let (lines, pixels) = mouse_wheel
+        .read()
+        .fold((0.0, 0.0), |(lines, pixels), event| match event.unit {
+            MouseScrollUnit::Line => (lines + event.y, pixels),
+            MouseScrollUnit::Pixel => (lines, pixels + event.y),
+        });
+    kinetic.retain(|entity, _| cam_q.contains(*entity));
+    key_held.retain(|entity, _| cam_q.contains(*entity));
+    let primary_window = primary_window_q.get_single().ok();
+    // Only the camera under the cursor is zoomed
+    let hovered = hovered_camera(
+        cam_q
+            .iter()
+            .filter(|(_, _, ctrl, _, _, _)| ctrl.enabled)
+            .map(|(entity, _, _, camera, _, _)| (entity, camera)),
+        primary_window,
+        |window| window_q.get(window).ok()?.cursor_position(),
+    );
+    for (entity, mut cam, cam_controls, camera, projection, ray_filter) in cam_q
+        .iter_mut()
+        .filter(|(_, _, ctrl, _, _, _)| ctrl.enabled)
+    {
+        let old_zoom = cam.target_zoom;
+        let blocked = pointer_blocked.0 || hovered != Some(entity);
+        let pixel_lines = if cam_controls.scroll_pixels_per_line > 0.0 {
+            pixels / cam_controls.scroll_pixels_per_line
+        } else {
+            0.0
+        };
+        let mut scroll = lines + pixel_lines;
+        let tilting = !cam_controls.modifiers_scroll_tilt.is_empty()
+            && keys.any_pressed(cam_controls.modifiers_scroll_tilt.iter().copied());
+        if !cam_controls.kinetic_zoom || blocked || tilting || lines != 0.0 {
+            // A mouse wheel (or moving off the camera) stops any kinetic zoom
+            kinetic.remove(&entity);
+        } else {
+            let dt = time.delta_secs();
+            let state = kinetic.entry(entity).or_default();
+            if pixel_lines != 0.0 {
+                // Trackpads don't send an event every frame, so measure the speed over the time
+                // since the last one
+                let speed = pixel_lines / (state.idle + dt).max(f32::EPSILON);
+                state.velocity = if state.velocity.signum() == speed.signum() {
+                    state.velocity.lerp(speed, KINETIC_ZOOM_SMOOTHING)
+                } else {
+                    speed
+                };
+                state.idle = 0.0;
+            } else {
+                state.idle += dt;
+                // Short gaps between events are part of the gesture
+                if state.idle > KINETIC_ZOOM_DELAY {
+                    scroll += state.velocity * dt;
+                    state.velocity *= (-cam_controls.kinetic_zoom_friction * dt).exp();
+                    if state.velocity.abs() < KINETIC_ZOOM_MIN_SPEED {
+                        kinetic.remove(&entity);
+                    }
+                }
+            }
+        }
+        let scroll_amount = if blocked {
+            0.0
+        } else if tilting {
+            // Tilting changes the target angle, so it's smoothed the same as the keys
+            if scroll != 0.0 {
+                cam.tilt(scroll * cam_controls.scroll_tilt_sensitivity);
+            }
+            0.0
+        } else {
+            cam_controls.zoom_input(scroll)
+        };
+        let zoom_amount = scroll_amount
+            + key_zoom_lines(
+                cam_controls,
+                &keys,
+                key_held.entry(entity).or_default(),
+                time.delta_secs(),
+            );
+        if cam_controls
+            .key_zoom_reset
+            .is_some_and(|key| keys.just_pressed(key))
+        {
+            let zoom = cam_controls.zoom_reset_level.clamp(0.0, 1.0);
+            if cam.target_zoom != zoom {
+                cam.target_zoom = zoom;
+            }
+            pending_height.remove(&entity);
+            pending_steps.remove(&entity);
+        }
+        match cam_controls.zoom_mode {
+            ZoomMode::Normalized => {
+                let new_zoom = (cam.target_zoom
+                    + zoom_amount * 0.5 * cam_controls.zoom_sensitivity)
+                    .clamp(0.0, 1.0);
+                if cam.target_zoom != new_zoom {
+                    cam.target_zoom = new_zoom;
+                }
+            }
+            ZoomMode::WorldUnits {
+                units_per_line,
+                max_rate,
+            } => {
+                if cam.height_max <= cam.height_min {
+                    continue;
+                }
+                // Zooming in lowers the camera, so a positive scroll is a negative height change
+                let pending = pending_height.entry(entity).or_insert(0.0);
+                *pending -= zoom_amount * units_per_line;
+                let max_step = max_rate * time.delta_secs();
+                let step = pending.clamp(-max_step, max_step);
+                *pending -= step;
+                let new_zoom = cam.zoom_at_height(cam.height_at_zoom(cam.target_zoom) + step);
+                // Drop whatever is left over once we hit either end of the zoom range, otherwise
+                // it would keep pushing against the limit after the user changes direction
+                if new_zoom == 0.0 || new_zoom == 1.0 {
+                    *pending = 0.0;
+                }
+                if cam.target_zoom != new_zoom {
+                    cam.target_zoom = new_zoom;
+                }
+            }
+            ZoomMode::Steps(ref steps) => {
+                let pending = pending_steps.entry(entity).or_insert(0.0);
+                *pending += zoom_amount;
+                let mut new_zoom = cam.target_zoom;
+                while pending.abs() >= 1.0 {
+                    let direction = pending.signum();
+                    *pending -= direction;
+                    let next_step = if direction > 0.0 {
+                        steps
+                            .iter()
+                            .copied()
+                            .filter(|step| *step > new_zoom + ZOOM_STEP_EPSILON)
+                            .reduce(f32::min)
+                    } else {
+                        steps
+                            .iter()
+                            .copied()
+                            .filter(|step| *step < new_zoom - ZOOM_STEP_EPSILON)
+                            .reduce(f32::max)
+                    };
+                    match next_step {
+                        Some(step) => new_zoom = step.clamp(0.0, 1.0),
+                        // Already at the last step in this direction
+                        None => *pending = 0.0,
+                    }
+                }
+                if cam.target_zoom != new_zoom {
+                    cam.target_zoom = new_zoom;
+                }
+            }
+        }
+
+        // Keys zoom towards the center of the screen, as the cursor may be anywhere
+        if cam_controls.zoom_to_cursor && scroll_amount != 0.0 && cam.target_zoom != old_zoom {
+            let cursor_position = camera_window(camera, primary_window)
+                .and_then(|window| window_q.get(window).ok())
+                .and_then(|window| window.cursor_position());
+            if let Some(cursor_position) = cursor_position {
+                zoom_towards_cursor(
+                    &mut cam,
+                    old_zoom,
+                    camera,
+                    projection,
+                    &ground,
+                    ray_filter,
+                    cursor_position,
+                );
+            }
+        }
+    }
+}
+
+/// How long `RtsCameraControls.key_zoom_in` or `key_zoom_out` has to be held before it starts
+/// repeating, in seconds.
+const KEY_ZOOM_REPEAT_DELAY: f32 = 0.3;
+
+/// Returns how many lines of zoom the zoom keys give this frame, where positive zooms in. `held`
+/// is how long the keys have been held.
+fn key_zoom_lines(
+    controls: &RtsCameraControls,
+    keys: &ButtonInput<KeyCode>,
+    held: &mut f32,
+    dt: f32,
+) -> f32 {
+    let pressed = |key: Option<KeyCode>| key.is_some_and(|key| keys.pressed(key));
+    let just_pressed = |key: Option<KeyCode>| key.is_some_and(|key| keys.just_pressed(key));
+    let direction = pressed(controls.key_zoom_in) as i8 - pressed(controls.key_zoom_out) as i8;
+    if direction == 0 {
+        *held = 0.0;
+        return 0.0;
+    }
+    if just_pressed(controls.key_zoom_in) || just_pressed(controls.key_zoom_out) {
+        *held = 0.0;
+        return direction as f32;
+    }
+    // Count the repeats that fell within this frame
+    let repeats = |held: f32| {
+        ((held - KEY_ZOOM_REPEAT_DELAY) * controls.key_zoom_repeat_rate.max(0.0))
+            .max(0.0)
+            .floor()
+    };
+    let before = repeats(*held);
+    *held += dt;
+    (repeats(*held) - before) * direction as f32
+}
+
+/// Moves `target_focus` so that the point on the ground under `cursor_position` stays under the
+/// cursor after `target_zoom` changed from `old_zoom`. This is done by scaling the focus's
+/// position around that point by how much the view shrank or grew, which for a perspective camera
+/// is proportional to the height, and for an orthographic camera is the projection's scale.
+fn zoom_towards_cursor<B: GroundBackend>(
+    cam: &mut RtsCamera,
+    old_zoom: f32,
+    camera: &Camera,
+    projection: &Projection,
+    ground: &RtsCameraGround<B>,
+    ray_filter: Option<&RtsCameraRayFilter>,
+    cursor_position: Vec2,
+) {
+    let (old_size, new_size) = match projection {
+        // The size of the view on the ground is proportional to the height and the tangent of
+        // half the field of view
+        Projection::Perspective(persp) => {
+            let size = |zoom: f32| {
+                let height = cam.height_at_zoom(cam.height_zoom(zoom, Some(projection)));
+                let fov = cam.fov_at_zoom(zoom, projection).unwrap_or(persp.fov);
+                height * (fov / 2.0).tan()
+            };
+            (size(old_zoom), size(cam.target_zoom))
+        }
+        Projection::Orthographic(_) => {
+            let (Some(old_scale), Some(new_scale)) = (
+                cam.ortho_scale_at_zoom(old_zoom, projection),
+                cam.ortho_scale_at_zoom(cam.target_zoom, projection),
+            ) else {
+                return;
+            };
+            (old_scale, new_scale)
+        }
+    };
+    if old_size <= 0.0 {
+        return;
+    }
+    let viewport = camera
+        .logical_viewport_rect()
+        .unwrap_or(Rect::from_corners(Vec2::ZERO, Vec2::INFINITY));
+    if !viewport.contains(cursor_position) {
+        return;
     }
+    // Cast from where the camera is heading rather than where it currently is, so that repeated
+    // scrolling stays anchored to the same point while smoothing catches up
+    let height_zoom = cam.height_zoom(old_zoom, Some(projection));
+    let old_tfm = cam.camera_transform(&cam.target_focus, height_zoom, cam.target_angle);
+    let ray = match projection {
+        // The field of view may not have caught up to the zoom either
+        Projection::Perspective(persp) => match cam.fov_at_zoom(old_zoom, projection) {
+            Some(fov) => camera.logical_viewport_size().map(|vp_size| {
+                let uv = (cursor_position - viewport.min) / vp_size;
+                let half_height = (fov / 2.0).tan();
+                let half_width = half_height * persp.aspect_ratio;
+                let view_dir = Vec3::new(
+                    (uv.x * 2.0 - 1.0) * half_width,
+                    (1.0 - uv.y * 2.0) * half_height,
+                    -1.0,
+                );
+                Ray3d::new(
+                    old_tfm.translation,
+                    Dir3::new_unchecked((old_tfm.rotation * view_dir).normalize()),
+                )
+            }),
+            None => camera
+                .viewport_to_world(&old_tfm.into(), cursor_position - viewport.min)
+                .ok(),
+        },
+        // The projection's area is for the current scale, so work out the ray ourselves
+        Projection::Orthographic(ortho) => camera.logical_viewport_size().map(|vp_size| {
+            let area_scale = old_size / ortho.scale;
+            let uv = (cursor_position - viewport.min) / vp_size;
+            let view_point = Vec2::new(
+                ortho.area.min.x.lerp(ortho.area.max.x, uv.x),
+                ortho.area.max.y.lerp(ortho.area.min.y, uv.y),
+            ) * area_scale;
+            Ray3d::new(
+                old_tfm.transform_point(view_point.extend(0.0)),
+                old_tfm.forward(),
+            )
+        }),
+    };
+    let Some(ray) = ray else {
+        return;
+    };
+    let focus = cam.target_focus.translation;
+    let hit = cam.cursor_ground_hit(ray, ground.cast_ray(ray, ray_filter));
+    let Some(point) = hit.or_else(|| {
+        ray.intersect_plane(focus, cam.ground_plane)
+            .map(|distance| ray.get_point(distance))
+    }) else {
+        return;
+    };
+    let new_focus = point + (focus - point) * (new_size / old_size);
+    cam.target_focus.translation = cam.with_height_of(new_focus, focus);
+}
+
+/// How far back the movement of a drag pan is averaged over to find the speed of a
+/// `RtsCameraControls.grab_fling` when it's released.
+const FLING_SAMPLE_WINDOW: Duration = Duration::from_millis(100);
+/// The speed (in world units per second) below which a `RtsCameraControls.grab_fling` stops.
+const FLING_MIN_SPEED: f32 = 0.05;
+
+/// The speed and timing of the pixel scrolling of a `RtsCameraControls.kinetic_zoom`.
+#[derive(Default)]
+pub struct KineticZoom {
+    /// In lines per second.
+    velocity: f32,
+    /// Seconds since the last pixel scroll event.
+    idle: f32,
+}
+
+/// How long without pixel scroll events before a `RtsCameraControls.kinetic_zoom` takes over.
+const KINETIC_ZOOM_DELAY: f32 = 0.05;
+/// How much each pixel scroll event moves the speed of a `RtsCameraControls.kinetic_zoom`
+/// towards its own speed.
+const KINETIC_ZOOM_SMOOTHING: f32 = 0.5;
+/// The speed (in lines per second) below which a `RtsCameraControls.kinetic_zoom` stops.
+const KINETIC_ZOOM_MIN_SPEED: f32 = 0.05;
+
+/// How close `RtsCamera.target_zoom` has to be to a `ZoomMode::Steps` step to count as being at
+/// that step.
+const ZOOM_STEP_EPSILON: f32 = 1e-3;
+
+/// How far from each edge of the screen edge pan kicks in (see `RtsCameraControls.edge_pan_width`),
+/// as a percentage of the screen's height.
+/// # Example
+/// ```
+/// # use bevy_rts_camera::EdgePanWidths;
+/// // No edge pan along the bottom, where the HUD is
+/// let widths = EdgePanWidths {
+///     bottom: 0.0,
+///     ..EdgePanWidths::all(0.05)
+/// };
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EdgePanWidths {
+    /// The width of the left edge.
+    pub left: f32,
+    /// The width of the right edge.
+    pub right: f32,
+    /// The width of the top edge.
+    pub top: f32,
+    /// The width of the bottom edge.
+    pub bottom: f32,
+}
+
+impl EdgePanWidths {
+    /// Uses the same width for every edge.
+    pub const fn all(width: f32) -> Self {
+        Self {
+            left: width,
+            right: width,
+            top: width,
+            bottom: width,
+        }
+    }
+}
+
+/// Determines how keyboard and edge panning along both axes at once is combined (see
+/// `RtsCameraControls.diagonal_pan`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DiagonalPan {
+    /// Panning diagonally is as fast as panning along one axis.
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::DiagonalPan;
+    /// let diagonal = DiagonalPan::Normalize.apply(Vec2::new(1.0, 1.0));
+    /// assert!((diagonal.length() - 1.0).abs() < 1e-6);
+    /// // Partial edge pan speeds are kept
+    /// assert_eq!(DiagonalPan::Normalize.apply(Vec2::new(0.5, 0.0)), Vec2::new(0.5, 0.0));
+    /// ```
+    #[default]
+    Normalize,
+    /// Each axis pans independently, so panning diagonally is faster than along one axis, like
+    /// many classic RTS games.
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::DiagonalPan;
+    /// assert_eq!(DiagonalPan::PerAxis.apply(Vec2::new(1.0, 1.0)), Vec2::new(1.0, 1.0));
+    /// assert_eq!(DiagonalPan::PerAxis.apply(Vec2::new(0.5, -0.2)), Vec2::new(0.5, -0.2));
+    /// ```
+    PerAxis,
+    /// Only the axis with the most input pans, so the camera never pans diagonally. When both
+    /// are equal, panning forward or backward wins.
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::DiagonalPan;
+    /// assert_eq!(DiagonalPan::DominantAxis.apply(Vec2::new(0.8, 0.3)), Vec2::new(0.8, 0.0));
+    /// assert_eq!(DiagonalPan::DominantAxis.apply(Vec2::new(1.0, -1.0)), Vec2::new(0.0, -1.0));
+    /// ```
+    DominantAxis,
+}
+
+impl DiagonalPan {
+    /// Combines pan input along both axes (each between `-1.0` and `1.0`, with `x` being right and
+    /// `y` being forward) into the direction and speed (as a fraction of
+    /// `RtsCameraControls.pan_speed`) to pan at.
+    pub fn apply(self, input: Vec2) -> Vec2 {
+        let input = input.clamp(Vec2::NEG_ONE, Vec2::ONE);
+        match self {
+            // Not normalized, so edge pan can be slower than full speed
+            DiagonalPan::Normalize => input.clamp_length_max(1.0),
+            DiagonalPan::PerAxis => input,
+            DiagonalPan::DominantAxis => {
+                if input.x.abs() > input.y.abs() {
+                    Vec2::new(input.x, 0.0)
+                } else {
+                    Vec2::new(0.0, input.y)
+                }
+            }
+        }
+    }
+}
+
+/// Determines how zoom input (e.g. the mouse wheel) changes the camera's zoom.
+/// To change how zoom maps to camera height, see `RtsCamera.zoom_curve`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ZoomMode {
+    /// Each scroll line changes `RtsCamera.target_zoom` by a fixed fraction of the `0.0..1.0` zoom
+    /// range, scaled by `RtsCameraControls.zoom_sensitivity`. The change in camera height per
+    /// line therefore grows with the distance between `height_min` and `height_max`.
+    Normalized,
+    /// Each scroll line changes the camera height by `units_per_line` world units, applied at no
+    /// more than `max_rate` world units per second. Gives predictable zoom speed regardless of how
+    /// large `height_max` is.
+    WorldUnits {
+        /// How many world units each scroll line changes the camera height by.
+        units_per_line: f32,
+        /// The maximum change in camera height, in world units per second.
+        max_rate: f32,
+    },
+    /// Each scroll line moves `RtsCamera.target_zoom` to the next of these zoom levels (each
+    /// between `0.0` and `1.0`), like many classic RTS games. The camera still moves between steps
+    /// smoothly (see `RtsCamera.zoom_smoothness`). Steps don't need to be in order.
+    /// # Example
+    /// ```
+    /// # use bevy_rts_camera::ZoomMode;
+    /// let zoom_mode = ZoomMode::Steps(vec![0.0, 0.3, 0.6, 1.0]);
+    /// ```
+    Steps(Vec<f32>),
 }
 
 pub fn pan(
-    mut cam_q: Query<(&mut RtsCamera, &RtsCameraControls)>,
+    mut cam_q: Query<
+        (
+            Entity,
+            &mut RtsCamera,
+            &RtsCameraControls,
+            &RtsCameraInputSuppression,
+            Option<&RtsCameraFollow>,
+            &Camera,
+        ),
+        PlayerControlled,
+    >,
     button_input: Res<ButtonInput<KeyCode>>,
     mouse_input: Res<ButtonInput<MouseButton>>,
-    primary_window_q: Query<&Window, With<PrimaryWindow>>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    window_q: Query<&Window>,
     time: Res<Time<Real>>,
+    mut commands: Commands,
+    pointer_blocked: Res<RtsCameraPointerBlocked>,
+    // The current pan velocity of each camera that's panning, as a fraction of `pan_speed`
+    mut velocities: Local<EntityHashMap<Vec3>>,
+    // Where the cursor last was within the window, for `edge_pan_when_cursor_outside`
+    mut last_cursor_positions: Local<EntityHashMap<Vec2>>,
 ) {
-    for (mut cam, controller) in cam_q.iter_mut().filter(|(_, ctrl)| ctrl.enabled) {
-        if controller
-            .button_drag
-            .map_or(false, |btn| mouse_input.pressed(btn))
+    velocities.retain(|entity, _| cam_q.contains(*entity));
+    last_cursor_positions.retain(|entity, _| cam_q.contains(*entity));
+    for (entity, mut cam, controller, suppression, follow, camera) in cam_q
+        .iter_mut()
+        .filter(|(_, _, ctrl, _, _, _)| ctrl.enabled)
+    {
+        if suppression.is_suppressed()
+            || controller
+                .button_drag
+                .is_some_and(|btn| mouse_input.pressed(btn))
         {
+            velocities.remove(&entity);
             continue;
         }
 
-        let mut delta = Vec3::ZERO;
+        // Relative to the focus, with +X being right and +Y being forward
+        let mut delta = Vec2::ZERO;
 
         // Keyboard pan
-        if button_input.pressed(controller.key_up) {
-            delta += Vec3::from(cam.target_focus.forward())
+        if button_input.any_pressed(controller.keys_up.iter().copied()) {
+            delta.y += 1.0;
         }
-        if button_input.pressed(controller.key_down) {
-            delta += Vec3::from(cam.target_focus.back())
+        if button_input.any_pressed(controller.keys_down.iter().copied()) {
+            delta.y -= 1.0;
         }
-        if button_input.pressed(controller.key_left) {
-            delta += Vec3::from(cam.target_focus.left())
+        if button_input.any_pressed(controller.keys_left.iter().copied()) {
+            delta.x -= 1.0;
         }
-        if button_input.pressed(controller.key_right) {
-            delta += Vec3::from(cam.target_focus.right())
+        if button_input.any_pressed(controller.keys_right.iter().copied()) {
+            delta.x += 1.0;
         }
 
         // Edge pan
-        if delta.length_squared() == 0.0 && !mouse_input.pressed(controller.button_rotate) {
-            if let Ok(primary_window) = primary_window_q.get_single() {
-                if let Some(cursor_position) = primary_window.cursor_position() {
-                    let win_w = primary_window.width();
-                    let win_h = primary_window.height();
-                    let pan_width = win_h * controller.edge_pan_width;
-                    // Pan left
-                    if cursor_position.x < pan_width {
-                        delta += Vec3::from(cam.target_focus.left())
+        if delta.length_squared() == 0.0
+            && !mouse_input.pressed(controller.button_rotate)
+            && !pointer_blocked.0
+        {
+            let window = camera_window(camera, primary_window_q.get_single().ok())
+                .and_then(|window| window_q.get(window).ok())
+                .filter(|window| window.focused || !controller.edge_pan_requires_focus);
+            if let Some(window) = window {
+                let cursor_position = match window.cursor_position() {
+                    Some(cursor_position) => {
+                        last_cursor_positions.insert(entity, cursor_position);
+                        Some(cursor_position)
                     }
-                    // Pan right
-                    if cursor_position.x > win_w - pan_width {
-                        delta += Vec3::from(cam.target_focus.right())
+                    None if controller.edge_pan_when_cursor_outside => {
+                        last_cursor_positions.get(&entity).copied()
                     }
-                    // Pan up
-                    if cursor_position.y < pan_width {
-                        delta += Vec3::from(cam.target_focus.forward())
+                    None => None,
+                };
+                if let Some(cursor_position) = cursor_position {
+                    // The area that edge pan applies to, in logical pixels
+                    let area = if controller.edge_pan_restrict_to_viewport {
+                        camera.logical_viewport_rect()
+                    } else {
+                        None
                     }
-                    // Pan down
-                    if cursor_position.y > win_h - pan_width {
-                        delta += Vec3::from(cam.target_focus.back())
+                    .unwrap_or(Rect::new(
+                        0.0,
+                        0.0,
+                        window.width(),
+                        window.height(),
+                    ));
+                    let excluded = controller
+                        .edge_pan_exclusions
+                        .iter()
+                        .any(|exclusion| exclusion.contains(cursor_position));
+                    if area.contains(cursor_position) && !excluded {
+                        let widths = &controller.edge_pan_width;
+                        // How fast to pan given how far into an edge (of the given width) the
+                        // cursor is
+                        let speed = |depth: f32, width: f32| {
+                            let width = area.height() * width;
+                            if width <= 0.0 || depth >= width {
+                                0.0
+                            } else if let Some(ramp) = controller.edge_pan_ramp {
+                                EasingCurve::new(0.0, 1.0, ramp).sample_clamped(1.0 - depth / width)
+                            } else {
+                                1.0
+                            }
+                        };
+                        // Pan left
+                        delta.x -= speed(cursor_position.x - area.min.x, widths.left);
+                        // Pan right
+                        delta.x += speed(area.max.x - cursor_position.x, widths.right);
+                        // Pan up
+                        delta.y += speed(cursor_position.y - area.min.y, widths.top);
+                        // Pan down
+                        delta.y -= speed(area.max.y - cursor_position.y, widths.bottom);
                     }
                 }
             }
         }
 
+        let delta = controller.diagonal_pan.apply(delta);
+        let mut input = cam.target_focus.right() * delta.x + cam.target_focus.forward() * delta.y;
+        if button_input.any_pressed(controller.keys_fast_pan.iter().copied()) {
+            input *= controller.fast_pan_multiplier;
+        }
+        let previous_velocity = velocities.get(&entity).copied().unwrap_or_default();
+        let ramp_time = if input == Vec3::ZERO {
+            controller.pan_deceleration_time
+        } else {
+            controller.pan_acceleration_time
+        };
+        let velocity = if ramp_time > 0.0 {
+            let max_change = time.delta_secs() / ramp_time;
+            previous_velocity + (input - previous_velocity).clamp_length_max(max_change)
+        } else {
+            input
+        };
+        if velocity == Vec3::ZERO {
+            velocities.remove(&entity);
+            continue;
+        }
+        velocities.insert(entity, velocity);
+        if input != Vec3::ZERO {
+            break_follow(&mut commands, entity, follow);
+        }
+
         let new_target = cam.target_focus.translation
-            + delta.normalize_or_zero()
+            + velocity
             * time.delta_secs()
             * controller.pan_speed
             // Scale based on zoom so it (roughly) feels the same speed at different zoom levels
-            * cam.target_zoom.remap(0.0, 1.0, 1.0, 0.5);
+            * controller.pan_speed_factor(cam.target_zoom);
         cam.target_focus.translation = new_target;
     }
 }
 
-pub fn grab_pan(
-    mut cam_q: Query<(
-        &Transform,
-        &GlobalTransform,
-        &mut RtsCamera,
-        &RtsCameraControls,
-        &Camera,
-        &Projection,
-    )>,
+pub fn grab_pan<B: GroundBackend>(
+    mut cam_q: Query<
+        (
+            &Transform,
+            &GlobalTransform,
+            &mut RtsCamera,
+            &RtsCameraControls,
+            &Camera,
+            &Projection,
+            Option<&RtsCameraRayFilter>,
+            (Entity, Option<&RtsCameraFollow>, &RtsCameraInputSuppression),
+        ),
+        PlayerControlled,
+    >,
     mut mouse_motion: EventReader<MouseMotion>,
     mouse_button: Res<ButtonInput<MouseButton>>,
-    mut ray_cast: MeshRayCast,
+    ground: RtsCameraGround<B>,
     mut ray_hit: Local<Option<Vec3>>,
-    ground_q: Query<Entity, With<Ground>>,
-    mut primary_window_q: Query<&mut Window, With<PrimaryWindow>>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    mut window_q: Query<&mut Window>,
     mut previous_mouse_grab_mode: Local<CursorGrabMode>,
+    mut held: Local<Option<(Entity, MouseButton)>>,
+    mut commands: Commands,
+    pointer_blocked: Res<RtsCameraPointerBlocked>,
+    input_enabled: Res<RtsCameraInputEnabled>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut fling: Local<GrabFling>,
+    time: Res<Time<Real>>,
 ) {
-    for (cam_tfm, cam_gtfm, mut cam, controller, camera, projection) in cam_q
+    let mouse_delta = mouse_motion.read().map(|e| e.delta).sum::<Vec2>();
+    let now = time.elapsed();
+    let dt = time.delta_secs();
+    let primary_window = primary_window_q.get_single().ok();
+    // Drags can only start on the camera under the cursor
+    let hovered = hovered_camera(
+        cam_q
+            .iter()
+            .filter(|(_, _, _, ctrl, _, _, _, _)| ctrl.enabled)
+            .map(|(_, _, _, _, camera, _, _, (entity, _, _))| (entity, camera)),
+        primary_window,
+        |window| window_q.get(window).ok()?.cursor_position(),
+    );
+    for (
+        cam_tfm,
+        cam_gtfm,
+        mut cam,
+        controller,
+        camera,
+        projection,
+        ray_filter,
+        (entity, follow, suppression),
+    ) in cam_q
         .iter_mut()
-        .filter(|(_, _, _, ctrl, _, _)| ctrl.enabled)
+        .filter(|(_, _, _, ctrl, _, _, _, _)| ctrl.enabled)
     {
-        let Some(drag_button) = controller.button_drag else {
+        let Some(mut window) =
+            camera_window(camera, primary_window).and_then(|window| window_q.get_mut(window).ok())
+        else {
             continue;
         };
-        let Ok(mut primary_window) = primary_window_q.get_single_mut() else {
-            return;
+        let held_button = held
+            .filter(|(held_entity, _)| *held_entity == entity)
+            .map(|(_, button)| button);
+
+        // If the drag button changed mid-drag (e.g. `toggle_drag_mode`) or input was disabled or
+        // suppressed, we'll never see the original button released, so end the drag now
+        let accepts_input = input_enabled.0 && !suppression.is_suppressed();
+        if held_button.is_some_and(|btn| Some(btn) != controller.button_drag || !accepts_input) {
+            *held = None;
+            *ray_hit = None;
+
+            window.cursor_options.grab_mode = *previous_mouse_grab_mode;
+            window.cursor_options.visible = true;
+        }
+
+        let Some(drag_button) = controller.button_drag.filter(|_| accepts_input) else {
+            fling.velocities.remove(&entity);
+            continue;
         };
 
-        if mouse_button.just_pressed(drag_button) && controller.lock_on_drag {
-            let Some(cursor_position) = primary_window.cursor_position() else {
-                return;
+        let start_drag = mouse_button.just_pressed(drag_button)
+            && modifier_held(&controller.modifiers_drag, &keys)
+            && !pointer_blocked.0
+            && hovered == Some(entity)
+            && held.is_none();
+        if start_drag {
+            *held = Some((entity, drag_button));
+            fling.velocities.remove(&entity);
+            fling.samples.clear();
+        }
+
+        if start_drag && controller.lock_on_drag {
+            let Some(cursor_position) = window.cursor_position() else {
+                continue;
             };
 
-            *previous_mouse_grab_mode = primary_window.cursor_options.grab_mode;
-            primary_window.cursor_options.grab_mode = CursorGrabMode::Locked;
-            primary_window.cursor_options.visible = false;
-
-            if let Ok(cursor_ray) = camera.viewport_to_world(cam_gtfm, cursor_position) {
-                *ray_hit = ray_cast
-                    .cast_ray(
-                        cursor_ray,
-                        &RayCastSettings {
-                            filter: &|entity| ground_q.get(entity).is_ok(),
-                            ..default()
-                        },
-                    )
-                    .first()
-                    .map(|(_, hit)| hit.point);
+            *previous_mouse_grab_mode = window.cursor_options.grab_mode;
+            window.cursor_options.grab_mode = CursorGrabMode::Locked;
+            window.cursor_options.visible = false;
+
+            let viewport_position =
+                cursor_position - camera.logical_viewport_rect().map_or(Vec2::ZERO, |r| r.min);
+            if let Ok(cursor_ray) = camera.viewport_to_world(cam_gtfm, viewport_position) {
+                *ray_hit =
+                    cam.cursor_ground_hit(cursor_ray, ground.cast_ray(cursor_ray, ray_filter));
             }
         }
 
-        if mouse_button.just_released(drag_button) {
+        if mouse_button.just_released(drag_button) && *held == Some((entity, drag_button)) {
+            *held = None;
             *ray_hit = None;
 
-            primary_window.cursor_options.grab_mode = *previous_mouse_grab_mode;
-            primary_window.cursor_options.visible = true;
-        }
-
-        if mouse_button.pressed(drag_button) {
-            let mut mouse_delta = mouse_motion.read().map(|e| e.delta).sum::<Vec2>();
-
-            let mut multiplier = 1.0;
-            let vp_size = camera.logical_viewport_size().unwrap();
-            match *projection {
-                Projection::Perspective(ref p) => {
-                    mouse_delta *= Vec2::new(p.fov * p.aspect_ratio, p.fov) / vp_size;
-                    multiplier = (*ray_hit).map_or_else(
-                        || cam_tfm.translation.distance(cam.focus.translation),
-                        |hit| hit.distance(cam_tfm.translation),
-                    );
-                }
-                Projection::Orthographic(ref p) => {
-                    mouse_delta *= Vec2::new(p.area.width(), p.area.height()) / vp_size;
+            if controller.grab_fling {
+                let (distance, duration) = fling
+                    .samples
+                    .iter()
+                    .fold((Vec3::ZERO, 0.0), |(distance, duration), (_, delta, dt)| {
+                        (distance + *delta, duration + dt)
+                    });
+                if duration > 0.0 {
+                    fling.velocities.insert(entity, distance / duration);
                 }
             }
+            fling.samples.clear();
 
-            let mut delta = Vec3::ZERO;
-            delta += cam.target_focus.forward() * mouse_delta.y;
-            delta += cam.target_focus.right() * -mouse_delta.x;
-            cam.target_focus.translation += delta * multiplier;
+            window.cursor_options.grab_mode = *previous_mouse_grab_mode;
+            window.cursor_options.visible = true;
+        }
+
+        if mouse_button.pressed(drag_button) && *held == Some((entity, drag_button)) {
+            let mouse_delta = controller.drag_input(mouse_delta);
+            let delta = grab_pan_offset(cam_tfm, &cam, camera, projection, *ray_hit, mouse_delta);
+            if delta != Vec3::ZERO {
+                cam.target_focus.translation += delta;
+                break_follow(&mut commands, entity, follow);
+            }
+            // Keep frames without movement too, so stopping before letting go doesn't fling
+            fling.samples.push((now, delta, dt));
+            fling
+                .samples
+                .retain(|(time, _, _)| now.saturating_sub(*time) < FLING_SAMPLE_WINDOW);
+        } else if let Some(velocity) = fling.velocities.get(&entity).copied() {
+            cam.target_focus.translation += velocity * dt;
+            let velocity = velocity * (-controller.grab_fling_friction * dt).exp();
+            if velocity.length() < FLING_MIN_SPEED {
+                fling.velocities.remove(&entity);
+            } else {
+                fling.velocities.insert(entity, velocity);
+            }
         }
     }
 }
 
-pub fn rotate(
-    mut cam_q: Query<(&mut RtsCamera, &RtsCameraControls)>,
+/// The state of `RtsCameraControls.grab_fling`.
+#[derive(Default)]
+pub struct GrabFling {
+    /// The movement of the focus in each recent frame of the current drag, along with the time of
+    /// the frame and its duration in seconds.
+    samples: Vec<(Duration, Vec3, f32)>,
+    /// The velocity of each camera that's been flung.
+    velocities: EntityHashMap<Vec3>,
+}
+
+/// Returns the camera (out of `cameras`) that the cursor is over, using `cursor_position` to get
+/// the position of the cursor in a window. If the viewports of several cameras overlap, the one
+/// that renders last (with the highest `Camera.order`) is picked.
+fn hovered_camera<'a>(
+    cameras: impl Iterator<Item = (Entity, &'a Camera)>,
+    primary_window: Option<Entity>,
+    cursor_position: impl Fn(Entity) -> Option<Vec2>,
+) -> Option<Entity> {
+    cameras
+        .filter(|(_, camera)| camera.is_active)
+        .filter(|(_, camera)| {
+            let Some(cursor_position) =
+                camera_window(camera, primary_window).and_then(&cursor_position)
+            else {
+                return false;
+            };
+            camera
+                .logical_viewport_rect()
+                .is_some_and(|viewport| viewport.contains(cursor_position))
+        })
+        .max_by_key(|(_, camera)| camera.order)
+        .map(|(entity, _)| entity)
+}
+
+/// Returns the window that `camera` renders to, if it renders to a window.
+fn camera_window(camera: &Camera, primary_window: Option<Entity>) -> Option<Entity> {
+    match camera.target {
+        RenderTarget::Window(window_ref) => window_ref
+            .normalize(primary_window)
+            .map(|window_ref| window_ref.entity()),
+        _ => None,
+    }
+}
+
+/// Stops the camera following an entity, if it is following one and should stop when panned.
+fn break_follow(commands: &mut Commands, entity: Entity, follow: Option<&RtsCameraFollow>) {
+    if follow.is_some_and(|follow| follow.break_on_pan) {
+        commands.entity(entity).remove::<RtsCameraFollow>();
+    }
+}
+
+/// Returns whether one of `modifiers` is held, or `true` if there are none.
+fn modifier_held(modifiers: &[KeyCode], keys: &ButtonInput<KeyCode>) -> bool {
+    modifiers.is_empty() || keys.any_pressed(modifiers.iter().copied())
+}
+
+/// How close (as a fraction of a step) the camera has to be to a `RtsCameraControls.rotation_snap`
+/// direction to count as facing it.
+const SNAP_EPSILON: f32 = 1e-3;
+
+pub fn rotate<B: GroundBackend>(
+    mut cam_q: Query<
+        (
+            Entity,
+            &mut RtsCamera,
+            &RtsCameraControls,
+            &Camera,
+            &GlobalTransform,
+            Option<&RtsCameraRayFilter>,
+        ),
+        PlayerControlled,
+    >,
     mouse_input: Res<ButtonInput<MouseButton>>,
     keys: Res<ButtonInput<KeyCode>>,
     mut mouse_motion: EventReader<MouseMotion>,
-    mut primary_window_q: Query<&mut Window, With<PrimaryWindow>>,
+    primary_window_q: Query<Entity, With<PrimaryWindow>>,
+    mut window_q: Query<&mut Window>,
     mut previous_mouse_grab_mode: Local<CursorGrabMode>,
+    mut held: Local<Option<(Entity, MouseButton)>>,
+    mut pivot: Local<Option<Vec3>>,
+    pointer_blocked: Res<RtsCameraPointerBlocked>,
+    input_enabled: Res<RtsCameraInputEnabled>,
+    time: Res<Time<Real>>,
+    ground: RtsCameraGround<B>,
 ) {
-    if let Ok(mut primary_window) = primary_window_q.get_single_mut() {
-        for (mut cam, controller) in cam_q.iter_mut().filter(|(_, ctrl)| ctrl.enabled) {
-            if mouse_input.just_pressed(controller.button_rotate) && controller.lock_on_rotate {
-                *previous_mouse_grab_mode = primary_window.cursor_options.grab_mode;
-                primary_window.cursor_options.grab_mode = CursorGrabMode::Locked;
-                primary_window.cursor_options.visible = false;
+    let mouse_delta = mouse_motion.read().map(|e| e.delta).sum::<Vec2>();
+    let primary_window = primary_window_q.get_single().ok();
+    // Rotation with the mouse can only start on the camera under the cursor
+    let hovered = hovered_camera(
+        cam_q
+            .iter()
+            .filter(|(_, _, ctrl, ..)| ctrl.enabled)
+            .map(|(entity, _, _, camera, ..)| (entity, camera)),
+        primary_window,
+        |window| window_q.get(window).ok()?.cursor_position(),
+    );
+    for (entity, mut cam, controller, camera, cam_gtfm, ray_filter) in
+        cam_q.iter_mut().filter(|(_, _, ctrl, ..)| ctrl.enabled)
+    {
+        let Some(mut window) =
+            camera_window(camera, primary_window).and_then(|window| window_q.get_mut(window).ok())
+        else {
+            continue;
+        };
+        let held_button = held
+            .filter(|(held_entity, _)| *held_entity == entity)
+            .map(|(_, button)| button);
+
+        // If the rotate button changed mid-rotation (e.g. `toggle_drag_mode`) or input was
+        // disabled, we'll never see the original button released, so end the rotation now
+        if held_button.is_some_and(|btn| btn != controller.button_rotate || !input_enabled.0) {
+            *held = None;
+            window.cursor_options.grab_mode = *previous_mouse_grab_mode;
+            window.cursor_options.visible = true;
+        }
+        if !input_enabled.0 {
+            continue;
+        }
+
+        let start_rotate = mouse_input.just_pressed(controller.button_rotate)
+            && modifier_held(&controller.modifiers_rotate, &keys)
+            && !pointer_blocked.0
+            && hovered == Some(entity)
+            && held.is_none();
+        if start_rotate {
+            *held = Some((entity, controller.button_rotate));
+            *pivot = None;
+            if controller.rotate_around_cursor {
+                if let Some(cursor_position) = window.cursor_position() {
+                    let viewport_position = cursor_position
+                        - camera.logical_viewport_rect().map_or(Vec2::ZERO, |r| r.min);
+                    if let Ok(cursor_ray) = camera.viewport_to_world(cam_gtfm, viewport_position) {
+                        *pivot = cam
+                            .cursor_ground_hit(cursor_ray, ground.cast_ray(cursor_ray, ray_filter));
+                    }
+                }
             }
+        }
 
-            if mouse_input.pressed(controller.button_rotate) {
-                let mouse_delta = mouse_motion.read().map(|e| e.delta).sum::<Vec2>();
-                // Adjust based on window size, so that moving mouse entire width of window
-                // will be one half rotation (180 degrees)
-                let delta_x = mouse_delta.x / primary_window.width() * PI;
-                cam.target_focus.rotate_local_y(-delta_x);
+        if start_rotate && controller.lock_on_rotate {
+            *previous_mouse_grab_mode = window.cursor_options.grab_mode;
+            window.cursor_options.grab_mode = CursorGrabMode::Locked;
+            window.cursor_options.visible = false;
+        }
+
+        let rotating = *held == Some((entity, controller.button_rotate));
+        if mouse_input.pressed(controller.button_rotate) && rotating {
+            // Adjust based on window size, so that moving mouse entire width of window
+            // will be one half rotation (180 degrees)
+            let delta_x = mouse_delta.x / window.width() * PI;
+            if delta_x != 0.0 {
+                let angle = controller.rotate_input(-delta_x);
+                if let Some(pivot) = *pivot {
+                    // Swing the focus around the pivot by the same amount the camera turns
+                    let rotation = Quat::from_axis_angle(*cam.target_focus.up(), angle);
+                    let offset = cam.target_focus.translation - pivot;
+                    cam.target_focus.translation = pivot + rotation * offset;
+                }
+                cam.target_focus.rotate_local_y(angle);
+            }
+            // Same scale as rotation, so the same mouse movement tilts as much as it rotates
+            let delta_y = mouse_delta.y / window.width()
+                * PI
+                * controller.rotate_sensitivity
+                * controller.mouse_tilt_sensitivity;
+            if controller.tilt_on_rotate && delta_y != 0.0 {
+                cam.tilt(delta_y);
+            }
+        } else {
+            let left = if keys.any_pressed(controller.keys_rotate_left.iter().copied()) {
+                1.0
             } else {
-                let left = if keys.pressed(controller.key_rotate_left) {
-                    1.0
-                } else {
-                    0.0
-                };
-                let right = if keys.pressed(controller.key_rotate_right) {
-                    1.0
-                } else {
-                    0.0
-                };
+                0.0
+            };
+            let right = if keys.any_pressed(controller.keys_rotate_right.iter().copied()) {
+                1.0
+            } else {
+                0.0
+            };
+
+            let delta = right - left;
+            if delta != 0.0 {
+                cam.target_focus
+                    .rotate_local_y(delta * controller.key_rotate_speed * time.delta_secs());
+            }
+        }
+
+        if let Some(step) = controller.rotation_snap.filter(|step| *step > 0.0) {
+            // Steps from the target, so pressing again before arriving goes a step further
+            let steps = cam.target_yaw() / step;
+            if keys.any_just_pressed(controller.keys_rotate_step_left.iter().copied()) {
+                cam.set_target_yaw((steps + SNAP_EPSILON).ceil() * step);
+            }
+            if keys.any_just_pressed(controller.keys_rotate_step_right.iter().copied()) {
+                cam.set_target_yaw((steps - SNAP_EPSILON).floor() * step);
+            }
+        }
 
-                let delta = right - left;
-                if delta != 0.0 {
-                    cam.target_focus.rotate_local_y(
-                        delta / primary_window.width() * PI * controller.key_rotate_speed,
-                    );
+        let tilt_up = keys.any_pressed(controller.keys_tilt_up.iter().copied());
+        let tilt_down = keys.any_pressed(controller.keys_tilt_down.iter().copied());
+        let tilt = (tilt_up as i8 - tilt_down as i8) as f32;
+        if tilt != 0.0 {
+            cam.tilt(tilt * controller.key_tilt_speed * time.delta_secs());
+        }
+
+        if mouse_input.just_released(controller.button_rotate) && rotating {
+            *held = None;
+            *pivot = None;
+            if let (Some(step), Some(soft_snap)) =
+                (controller.rotation_snap, controller.rotation_soft_snap)
+            {
+                let yaw = cam.target_yaw();
+                let snapped = (yaw / step).round() * step;
+                if step > 0.0 && (yaw - snapped).abs() <= soft_snap {
+                    cam.set_target_yaw(snapped);
                 }
             }
+            window.cursor_options.grab_mode = *previous_mouse_grab_mode;
+            window.cursor_options.visible = true;
+        }
+    }
+}
 
-            if mouse_input.just_released(controller.button_rotate) {
-                primary_window.cursor_options.grab_mode = *previous_mouse_grab_mode;
-                primary_window.cursor_options.visible = true;
+pub fn face_direction(
+    mut cam_q: Query<(&mut RtsCamera, &RtsCameraControls), PlayerControlled>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    for (mut cam, controller) in cam_q.iter_mut().filter(|(_, ctrl)| ctrl.enabled) {
+        // Each direction is a quarter turn clockwise (when looking down) from the previous one.
+        // Rotating around up by a negative angle turns clockwise, e.g. -90 degrees faces east.
+        let cardinal = controller.keys_face_cardinal.map(|keys| (keys, 0.0));
+        let diagonal = controller.keys_face_diagonal.map(|keys| (keys, FRAC_PI_4));
+        for (keys_face, offset) in cardinal.into_iter().chain(diagonal) {
+            if let Some(i) = keys_face.iter().position(|key| keys.just_pressed(*key)) {
+                cam.set_target_yaw(-(offset + i as f32 * FRAC_PI_2));
             }
         }
+        if controller
+            .key_reset_orientation
+            .is_some_and(|key| keys.just_pressed(key))
+        {
+            cam.reset_orientation();
+        }
+    }
+}
+
+pub fn jump_to_hotspot(
+    cam_q: Query<(Entity, &RtsCameraControls), PlayerControlled>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut hotspots: ResMut<RtsCameraHotspots>,
+    mut jump_to: EventWriter<RtsCameraJumpTo>,
+) {
+    for (entity, controller) in cam_q.iter().filter(|(_, ctrl)| ctrl.enabled) {
+        if !controller
+            .key_jump_to_hotspot
+            .is_some_and(|key| keys.just_pressed(key))
+        {
+            continue;
+        }
+        if let Some(hotspot) = hotspots.next_hotspot() {
+            jump_to.send(RtsCameraJumpTo {
+                camera: Some(entity),
+                ..RtsCameraJumpTo::new(hotspot.position)
+            });
+        }
+    }
+}
+
+pub fn bookmark(
+    mut cam_q: Query<
+        (
+            Entity,
+            &mut RtsCamera,
+            &RtsCameraControls,
+            Option<&RtsCameraFollow>,
+        ),
+        PlayerControlled,
+    >,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    mut commands: Commands,
+) {
+    for (entity, mut cam, controller, follow) in
+        cam_q.iter_mut().filter(|(_, _, ctrl, _)| ctrl.enabled)
+    {
+        let Some(slot) = controller
+            .keys_bookmark
+            .iter()
+            .position(|key| keys.just_pressed(*key))
+        else {
+            continue;
+        };
+        if keys.pressed(controller.key_save_bookmark) {
+            bookmarks.save(slot, cam.save_state());
+        } else if let Some(state) = bookmarks.get(slot) {
+            cam.restore_state(state);
+            break_follow(&mut commands, entity, follow);
+        }
     }
 }
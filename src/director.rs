@@ -0,0 +1,141 @@
+use crate::{CameraTime, RtsCamera, RtsCameraFollow};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Resource that queues up high level camera commands, which are carried out in order, for
+/// spectator modes, replays, or casting tools. The camera moves smoothly as usual, but
+/// `RtsCameraControls` is ignored by the directed cameras until the queue is empty.
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCameraDirector, RtsCameraDirectorCommand};
+/// fn show_battle(mut director: ResMut<RtsCameraDirector>, hero: Entity) {
+///     director
+///         .push(RtsCameraDirectorCommand::JumpTo(Vec3::new(30.0, 0.0, 10.0)))
+///         .push(RtsCameraDirectorCommand::SetZoom(0.6))
+///         .push(RtsCameraDirectorCommand::Hold(Duration::from_secs(3)))
+///         .push(RtsCameraDirectorCommand::Follow(hero))
+///         .push(RtsCameraDirectorCommand::Hold(Duration::from_secs(5)));
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct RtsCameraDirector {
+    /// The camera that commands are applied to, or `None` for all cameras.
+    /// Defaults to `None`.
+    pub camera: Option<Entity>,
+    queue: VecDeque<RtsCameraDirectorCommand>,
+    hold: Option<Duration>,
+}
+
+impl RtsCameraDirector {
+    /// Adds a command to the end of the queue.
+    pub fn push(&mut self, command: RtsCameraDirectorCommand) -> &mut Self {
+        self.queue.push_back(command);
+        self
+    }
+
+    /// Removes all queued commands and ends the current `Hold`, returning control to the player.
+    /// Anything already started (e.g. following an entity) carries on.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.hold = None;
+    }
+
+    /// Returns whether all commands have been carried out, including waiting out any `Hold`.
+    pub fn is_idle(&self) -> bool {
+        self.queue.is_empty() && self.hold.is_none()
+    }
+
+    /// Returns the commands that haven't been carried out yet, in order.
+    pub fn queued(&self) -> impl Iterator<Item = &RtsCameraDirectorCommand> {
+        self.queue.iter()
+    }
+}
+
+/// A command for `RtsCameraDirector`. All commands except `Hold` are carried out immediately, one
+/// after the other.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RtsCameraDirectorCommand {
+    /// Moves the focus to this position. The height is replaced by the height of the ground. Stops
+    /// following an entity.
+    JumpTo(Vec3),
+    /// Follows an entity (see `RtsCameraFollow`) until told otherwise, or until the player pans
+    /// away once the queue is empty.
+    Follow(Entity),
+    /// Stops following an entity.
+    StopFollowing,
+    /// Changes the target zoom (see `RtsCamera.target_zoom`).
+    SetZoom(f32),
+    /// Rotates the focus to this yaw in radians, around the normal of `RtsCamera.ground_plane`,
+    /// where `0.0` faces forward (-Z) and positive values rotate counterclockwise when looking
+    /// down.
+    SetYaw(f32),
+    /// Waits this long before carrying out the next command.
+    Hold(Duration),
+}
+
+/// Marks a camera that's being controlled by `RtsCameraDirector`.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub(crate) struct DirectedCamera;
+
+pub(crate) fn run_director(
+    mut director: ResMut<RtsCameraDirector>,
+    mut cam_q: Query<(Entity, &mut RtsCamera, Has<DirectedCamera>)>,
+    time: CameraTime,
+    mut commands: Commands,
+) {
+    if director.is_idle() && !cam_q.iter().any(|(_, _, directed)| directed) {
+        return;
+    }
+    if let Some(hold) = director.hold {
+        let hold = hold.saturating_sub(time.delta());
+        director.hold = (!hold.is_zero()).then_some(hold);
+    }
+    while director.hold.is_none() {
+        let Some(command) = director.queue.pop_front() else {
+            break;
+        };
+        if let RtsCameraDirectorCommand::Hold(duration) = command {
+            director.hold = (!duration.is_zero()).then_some(duration);
+            continue;
+        }
+        for (entity, mut cam, _) in cam_q.iter_mut() {
+            if director.camera.is_some_and(|camera| camera != entity) {
+                continue;
+            }
+            match command {
+                RtsCameraDirectorCommand::JumpTo(position) => {
+                    cam.target_focus.translation = position;
+                    cam.flight = None;
+                    commands.entity(entity).remove::<RtsCameraFollow>();
+                }
+                RtsCameraDirectorCommand::Follow(target) => {
+                    commands.entity(entity).insert(RtsCameraFollow::new(target));
+                }
+                RtsCameraDirectorCommand::StopFollowing => {
+                    commands.entity(entity).remove::<RtsCameraFollow>();
+                }
+                RtsCameraDirectorCommand::SetZoom(zoom) => {
+                    cam.target_zoom = zoom.clamp(0.0, 1.0);
+                }
+                RtsCameraDirectorCommand::SetYaw(yaw) => {
+                    cam.target_focus.rotation = cam.plane_rotation() * Quat::from_rotation_y(yaw);
+                }
+                RtsCameraDirectorCommand::Hold(_) => {}
+            }
+        }
+    }
+
+    // Keep the player's controls away from the cameras being directed
+    let active = !director.is_idle();
+    for (entity, _, directed) in cam_q.iter() {
+        let target = active && director.camera.is_none_or(|camera| camera == entity);
+        if target && !directed {
+            commands.entity(entity).insert(DirectedCamera);
+        } else if !target && directed {
+            commands.entity(entity).remove::<DirectedCamera>();
+        }
+    }
+}
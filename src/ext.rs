@@ -0,0 +1,121 @@
+use crate::RtsCamera;
+use bevy::prelude::*;
+
+/// Extension trait for moving an `RtsCamera` the same way the built-in controllers do, for
+/// writing custom controllers (e.g. for gamepads or an input manager) without copying the
+/// controller code. Everything changes the camera's targets, so movement is smoothed as usual.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, RtsCameraExt};
+/// fn gamepad_controls(
+///     mut cam_q: Query<&mut RtsCamera>,
+///     gamepad_q: Query<&Gamepad>,
+///     time: Res<Time>,
+/// ) {
+///     let Ok(gamepad) = gamepad_q.get_single() else {
+///         return;
+///     };
+///     for mut cam in cam_q.iter_mut() {
+///         let right = cam.target_focus.right() * gamepad.left_stick().x;
+///         let forward = cam.target_focus.forward() * gamepad.left_stick().y;
+///         cam.pan_world((right + forward) * 20.0 * time.delta_secs());
+///         cam.orbit_yaw(-gamepad.right_stick().x * 2.0 * time.delta_secs());
+///         cam.zoom_by(gamepad.right_stick().y * time.delta_secs());
+///     }
+/// }
+/// ```
+pub trait RtsCameraExt {
+    /// Moves the focus by `delta` world units. Only movement along `RtsCamera.ground_plane` is
+    /// kept, as the height of the focus follows the ground.
+    fn pan_world(&mut self, delta: Vec3);
+
+    /// Moves the focus so the ground under the cursor moves by `delta` logical pixels, the same as
+    /// dragging the ground with `RtsCameraControls.button_drag`.
+    fn pan_screen(&mut self, delta: Vec2, camera: &Camera, projection: &Projection);
+
+    /// Rotates the camera around the focus by `angle` radians, where positive values rotate
+    /// counterclockwise when looking down.
+    fn orbit_yaw(&mut self, angle: f32);
+
+    /// Changes the target zoom by `amount`, where the full zoom range is `1.0` and positive values
+    /// zoom in.
+    fn zoom_by(&mut self, amount: f32);
+
+    /// Moves the focus straight to `position`, stopping any `fly_to` in progress. The height is
+    /// replaced by the height of the ground.
+    fn jump_to(&mut self, position: Vec3);
+}
+
+impl RtsCameraExt for RtsCamera {
+    fn pan_world(&mut self, delta: Vec3) {
+        let delta = delta.reject_from_normalized(*self.ground_plane.normal);
+        if delta != Vec3::ZERO {
+            self.target_focus.translation += delta;
+        }
+    }
+
+    fn pan_screen(&mut self, delta: Vec2, camera: &Camera, projection: &Projection) {
+        let cam_tfm = self.camera_transform(
+            &self.focus,
+            self.height_zoom(self.zoom, Some(projection)),
+            self.angle,
+        );
+        let offset = grab_pan_offset(&cam_tfm, self, camera, projection, None, delta);
+        self.pan_world(offset);
+    }
+
+    fn orbit_yaw(&mut self, angle: f32) {
+        if angle != 0.0 {
+            self.target_focus.rotate_local_y(angle);
+        }
+    }
+
+    fn zoom_by(&mut self, amount: f32) {
+        let target_zoom = (self.target_zoom + amount).clamp(0.0, 1.0);
+        if self.target_zoom != target_zoom {
+            self.target_zoom = target_zoom;
+        }
+    }
+
+    fn jump_to(&mut self, position: Vec3) {
+        self.target_focus.translation = position;
+        self.flight = None;
+    }
+}
+
+/// Converts a drag of `screen_delta` logical pixels into how far the focus should move so that
+/// the ground appears to follow the cursor. `grab_point` is the point on the ground that was
+/// grabbed, if known.
+pub(crate) fn grab_pan_offset(
+    cam_tfm: &Transform,
+    cam: &RtsCamera,
+    camera: &Camera,
+    projection: &Projection,
+    grab_point: Option<Vec3>,
+    mut screen_delta: Vec2,
+) -> Vec3 {
+    let Some(vp_size) = camera.logical_viewport_size() else {
+        return Vec3::ZERO;
+    };
+    let mut multiplier = 1.0;
+    match *projection {
+        Projection::Perspective(ref p) => {
+            screen_delta *= Vec2::new(p.fov * p.aspect_ratio, p.fov) / vp_size;
+            multiplier = grab_point.map_or_else(
+                || cam_tfm.translation.distance(cam.focus.translation),
+                |hit| hit.distance(cam_tfm.translation),
+            );
+        }
+        Projection::Orthographic(ref p) => {
+            screen_delta *= Vec2::new(p.area.width(), p.area.height()) / vp_size;
+            // The ground is tilted away from the view, so moving along it moves less on screen
+            screen_delta.y /= cam.angle.cos().max(f32::EPSILON);
+        }
+    }
+
+    let mut delta = Vec3::ZERO;
+    delta += cam.target_focus.forward() * screen_delta.y;
+    delta += cam.target_focus.right() * -screen_delta.x;
+    delta * multiplier
+}
@@ -0,0 +1,106 @@
+use crate::Ground;
+use bevy::gltf::GltfExtras;
+use bevy::prelude::*;
+
+pub struct GroundTaggingPlugin;
+
+impl Plugin for GroundTaggingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            tag_ground.run_if(resource_exists::<GroundTagConfig>),
+        );
+    }
+}
+
+/// Resource that marks entities spawned from glTF scenes (e.g. maps made in Blender) as `Ground`
+/// when their name or custom properties say they're terrain, so scenes don't need to be
+/// post-processed by hand. Entities are checked when they're spawned, and matching entities get
+/// `Ground` (which makes every mesh below them ground too). Does nothing until the resource is
+/// inserted.
+/// Requires the `gltf_tagging` feature.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{GroundTagConfig, RtsCameraPlugin};
+/// # fn main() {
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(RtsCameraPlugin::default())
+///     .insert_resource(GroundTagConfig {
+///         names: vec!["Terrain".to_string()],
+///         ..default()
+///     })
+///     .run();
+/// # }
+/// ```
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct GroundTagConfig {
+    /// Entities with one of these `Name`s are ground. Names with a numbered suffix, like the
+    /// `Terrain.001` that Blender gives duplicated objects, also match.
+    /// Defaults to `[]`.
+    pub names: Vec<String>,
+    /// Entities whose `GltfExtras` (custom properties in Blender) have this key are ground, unless
+    /// its value is `false`, `0`, or `null`. `None` to only match by name.
+    /// Defaults to `Some("ground")`.
+    pub extras_key: Option<String>,
+}
+
+impl Default for GroundTagConfig {
+    fn default() -> Self {
+        GroundTagConfig {
+            names: Vec::new(),
+            extras_key: Some("ground".to_string()),
+        }
+    }
+}
+
+impl GroundTagConfig {
+    /// Returns whether an entity with `name` and `extras` should be ground.
+    fn matches(&self, name: Option<&Name>, extras: Option<&GltfExtras>) -> bool {
+        let name_matches = name.is_some_and(|name| {
+            self.names
+                .iter()
+                .any(|expected| name_matches(name.as_str(), expected))
+        });
+        let extras_match = match (&self.extras_key, extras) {
+            (Some(key), Some(extras)) => serde_json::from_str::<serde_json::Value>(&extras.value)
+                .ok()
+                .and_then(|value| value.get(key).cloned())
+                .is_some_and(|value| {
+                    !matches!(
+                        value,
+                        serde_json::Value::Null | serde_json::Value::Bool(false)
+                    ) && value.as_f64() != Some(0.0)
+                }),
+            _ => false,
+        };
+        name_matches || extras_match
+    }
+}
+
+/// Returns whether `name` is `expected`, optionally followed by a numbered suffix like `.001`.
+fn name_matches(name: &str, expected: &str) -> bool {
+    let Some(suffix) = name.strip_prefix(expected) else {
+        return false;
+    };
+    match suffix.strip_prefix('.') {
+        Some(number) => !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()),
+        None => suffix.is_empty(),
+    }
+}
+
+/// Entities that were just spawned (or named) and aren't `Ground` yet.
+type NewUntagged = (Or<(Added<Name>, Added<GltfExtras>)>, Without<Ground>);
+
+fn tag_ground(
+    config: Res<GroundTagConfig>,
+    spawned_q: Query<(Entity, Option<&Name>, Option<&GltfExtras>), NewUntagged>,
+    mut commands: Commands,
+) {
+    for (entity, name, extras) in spawned_q.iter() {
+        if config.matches(name, extras) {
+            commands.entity(entity).insert(Ground);
+        }
+    }
+}
@@ -0,0 +1,686 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::ray_cast::BatchRayCast;
+use crate::{Ground, NotGround, RtsCameraRayFilter};
+use bevy::ecs::entity::{EntityHashMap, EntityHashSet};
+use bevy::ecs::system::{ReadOnlySystemParam, StaticSystemParam, SystemParam, SystemParamItem};
+use bevy::math::bounding::Aabb2d;
+use bevy::prelude::*;
+use bevy::render::primitives::Aabb;
+use bevy::utils::HashMap;
+
+/// The height that `RtsCameraGround::sample_ground_height` casts down from. Ground above this height
+/// will not be found.
+const SAMPLE_HEIGHT: f32 = 10_000.0;
+
+/// How many times `HeightmapGround` halves the distance between the last point above the ground
+/// and the first point below it, to find where a ray crosses the ground.
+const HEIGHTMAP_REFINE_ITERATIONS: usize = 16;
+
+/// How wide (in world units) each cell of `GroundIndex` is.
+const GROUND_INDEX_CELL_SIZE: f32 = 16.0;
+/// Ground meshes covering more cells than this in either direction aren't put in the cells, and
+/// are tested by every ray instead.
+const GROUND_INDEX_MAX_CELLS: i32 = 64;
+/// How many cells a ray is traced through before giving up and testing every ground mesh.
+const GROUND_INDEX_MAX_STEPS: usize = 1024;
+
+/// System param that gives access to the same ground information the RTS camera uses to follow
+/// the terrain. Useful for gameplay code that needs to know the height of the ground, e.g. when
+/// placing buildings or projecting decals. Uses `HeightmapGround` if it exists, otherwise ray
+/// casts against meshes marked with `Ground` (or uses the `GroundBackend` given to
+/// `RtsCameraPlugin::with_ground_backend`).
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::RtsCameraGround;
+/// fn place_building(ground: RtsCameraGround) {
+///     if let Some(height) = ground.sample_ground_height(10.0, -5.0) {
+///         info!("Building placed at height {height}");
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct RtsCameraGround<'w, 's, B: GroundBackend = MeshGround> {
+    heightmap: Option<Res<'w, HeightmapGround>>,
+    backend: StaticSystemParam<'w, 's, <B as GroundBackend>::Param>,
+}
+
+impl<B: GroundBackend> RtsCameraGround<'_, '_, B> {
+    /// Returns the height of the highest ground (anything marked with `Ground`) at the given
+    /// world XZ coordinates, or `None` if there is no ground there.
+    pub fn sample_ground_height(&self, x: f32, z: f32) -> Option<f32> {
+        self.cast_ray(
+            Ray3d::new(Vec3::new(x, SAMPLE_HEIGHT, z), Dir3::NEG_Y),
+            None,
+        )
+        .map(|point| point.y)
+    }
+
+    /// Returns the point on the ground under `viewport_position` (e.g. the cursor position, in
+    /// logical pixels relative to the camera's viewport) for a camera, if any. Pass the camera's
+    /// `RtsCameraRayFilter`, if it has one, to hit the same ground the camera follows.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy::window::PrimaryWindow;
+    /// # use bevy_rts_camera::{RtsCamera, RtsCameraGround, RtsCameraRayFilter};
+    /// fn ground_under_cursor(
+    ///     cam_q: Query<(&Camera, &GlobalTransform, Option<&RtsCameraRayFilter>), With<RtsCamera>>,
+    ///     window_q: Query<&Window, With<PrimaryWindow>>,
+    ///     ground: RtsCameraGround,
+    /// ) {
+    ///     let Some(cursor) = window_q.single().cursor_position() else {
+    ///         return;
+    ///     };
+    ///     let (camera, cam_tfm, filter) = cam_q.single();
+    ///     if let Some(point) = ground.viewport_to_ground(camera, cam_tfm, cursor, filter) {
+    ///         info!("Cursor is over {point}");
+    ///     }
+    /// }
+    /// ```
+    pub fn viewport_to_ground(
+        &self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        viewport_position: Vec2,
+        filter: Option<&RtsCameraRayFilter>,
+    ) -> Option<Vec3> {
+        let ray = camera
+            .viewport_to_world(camera_transform, viewport_position)
+            .ok()?;
+        self.cast_ray(ray, filter)
+    }
+
+    /// Returns the first point on the ground hit by `ray`, if any. Pass the camera's
+    /// `RtsCameraRayFilter`, if it has one, to hit the same ground the camera follows.
+    pub fn cast_ray(&self, ray: Ray3d, filter: Option<&RtsCameraRayFilter>) -> Option<Vec3> {
+        self.cast_rays(&[(ray, filter)])[0]
+    }
+
+    /// Casts all `rays` together in a single pass over the ground, returning the first point on
+    /// the ground hit by each.
+    pub(crate) fn cast_rays(
+        &self,
+        rays: &[(Ray3d, Option<&RtsCameraRayFilter>)],
+    ) -> Vec<Option<Vec3>> {
+        if let Some(heightmap) = &self.heightmap {
+            return rays
+                .iter()
+                .map(|(ray, _)| heightmap.cast_ray(*ray))
+                .collect();
+        }
+        B::cast_rays(&self.backend, rays)
+    }
+}
+
+/// What the camera ray casts against to find the ground, e.g. a physics engine's spatial queries
+/// instead of the ground meshes. Set with `RtsCameraPlugin::with_ground_backend`, which also makes
+/// `RtsCameraGround` use it. `HeightmapGround` still takes precedence over the backend while it
+/// exists. `AvianGround` (with the `avian3d` feature) and `RapierGround` (with the
+/// `bevy_rapier3d` feature) ray cast against physics colliders instead.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{GroundBackend, RtsCameraPlugin, RtsCameraRayFilter};
+/// #[derive(Resource)]
+/// struct SeaLevel(f32);
+///
+/// /// Treats the sea as the ground
+/// struct SeaGround;
+///
+/// impl GroundBackend for SeaGround {
+///     type Param = Res<'static, SeaLevel>;
+///
+///     fn cast_rays(
+///         sea_level: &Res<SeaLevel>,
+///         rays: &[(Ray3d, Option<&RtsCameraRayFilter>)],
+///     ) -> Vec<Option<Vec3>> {
+///         let plane = InfinitePlane3d::new(Vec3::Y);
+///         rays.iter()
+///             .map(|(ray, _)| {
+///                 let origin = Vec3::new(0.0, sea_level.0, 0.0);
+///                 ray.intersect_plane(origin, plane).map(|dist| ray.get_point(dist))
+///             })
+///             .collect()
+///     }
+/// }
+///
+/// App::new()
+///     .insert_resource(SeaLevel(2.0))
+///     .add_plugins((
+///         DefaultPlugins,
+///         RtsCameraPlugin::default().with_ground_backend::<SeaGround>(),
+///     ));
+/// ```
+pub trait GroundBackend: Send + Sync + 'static {
+    /// The system param needed to cast rays against the ground.
+    type Param: ReadOnlySystemParam;
+
+    /// Casts all `rays`, returning the first point on the ground hit by each (in the same order
+    /// as `rays`). Each ray comes with the `RtsCameraRayFilter` of the camera it's cast for, if it
+    /// has one.
+    fn cast_rays(
+        param: &SystemParamItem<Self::Param>,
+        rays: &[(Ray3d, Option<&RtsCameraRayFilter>)],
+    ) -> Vec<Option<Vec3>>;
+}
+
+/// The default `GroundBackend`, which ray casts against meshes marked with `Ground`.
+pub struct MeshGround;
+
+/// The system param used by `MeshGround`.
+#[derive(SystemParam)]
+pub struct MeshGroundParam<'w, 's> {
+    ray_cast: BatchRayCast<'w, 's, With<GroundMesh>>,
+    mesh_q: Query<'w, 's, &'static GroundMesh>,
+    index: Option<Res<'w, GroundIndex>>,
+}
+
+impl GroundBackend for MeshGround {
+    type Param = MeshGroundParam<'static, 'static>;
+
+    fn cast_rays(
+        param: &MeshGroundParam,
+        rays: &[(Ray3d, Option<&RtsCameraRayFilter>)],
+    ) -> Vec<Option<Vec3>> {
+        let ray_list = rays.iter().map(|(ray, _)| *ray).collect::<Vec<_>>();
+        // Only test the meshes in the cells the rays pass through, if that's known
+        let candidates = param
+            .index
+            .as_ref()
+            .and_then(|index| index.candidates(&ray_list));
+        param
+            .ray_cast
+            .cast_rays_among(&ray_list, candidates.as_ref(), |i, entity| {
+                param
+                    .mesh_q
+                    .get(entity)
+                    .is_ok_and(|mesh| mesh.accepts(entity, rays[i].1))
+            })
+            .into_iter()
+            .map(|hit| hit.map(|hit| hit.point))
+            .collect()
+    }
+}
+
+/// Resource that replaces ray casting against `Ground` meshes with a height function, e.g. an
+/// analytic function for procedural terrain, or a heightmap image. Much cheaper than ray casting
+/// against large terrain meshes. While this resource exists, it's used for everything the camera
+/// needs the ground for (following the terrain, grab pan, etc.), as well as by `RtsCameraGround`.
+/// `RtsCameraRayFilter` has no effect on it.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::HeightmapGround;
+/// fn setup(mut commands: Commands) {
+///     // Rolling hills
+///     commands.insert_resource(HeightmapGround::new(|pos| {
+///         Some((pos.x * 0.1).sin() * (pos.y * 0.1).cos() * 3.0)
+///     }));
+/// }
+/// ```
+#[derive(Resource, Clone)]
+pub struct HeightmapGround {
+    height: Arc<dyn Fn(Vec2) -> Option<f32> + Send + Sync>,
+    /// How far apart the points along a ray are when looking for where it hits the ground, for
+    /// rays that aren't pointing straight down. Smaller values are more accurate on steep or
+    /// bumpy terrain, but slower.
+    /// Defaults to `0.5`.
+    pub ray_step: f32,
+    /// How far along a ray to look for the ground, for rays that aren't pointing straight down.
+    /// Defaults to `1000.0`.
+    pub ray_max_distance: f32,
+}
+
+impl HeightmapGround {
+    /// Creates a heightmap from a function that returns the height of the ground at the given
+    /// world position, where X is world X and Y is world Z. Return `None` where there is no
+    /// ground.
+    pub fn new(height: impl Fn(Vec2) -> Option<f32> + Send + Sync + 'static) -> Self {
+        Self {
+            height: Arc::new(height),
+            ray_step: 0.5,
+            ray_max_distance: 1000.0,
+        }
+    }
+
+    /// Creates a heightmap from the red channel of an image, stretched over `area` of the world
+    /// (where X is world X and Y is world Z, with the top row of the image at `area.min.y`).
+    /// Black is `min_height` and white is `max_height`, and heights are interpolated between
+    /// pixels. There is no ground outside of `area`. The image's data is copied, so it can be
+    /// dropped or modified afterwards.
+    pub fn from_image(image: &Image, area: Rect, min_height: f32, max_height: f32) -> Self {
+        let size = image.size();
+        let heights = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| (x, y)))
+            .map(|(x, y)| match image.get_color_at(x, y) {
+                // Use the stored value as is, heightmaps aren't really sRGB
+                Ok(Color::Srgba(color)) => color.red,
+                Ok(color) => color.to_linear().red,
+                Err(_) => 0.0,
+            })
+            .collect::<Vec<_>>();
+        Self::new(move |pos| {
+            if size.x == 0 || size.y == 0 || !area.contains(pos) {
+                return None;
+            }
+            let max_pixel = (size - UVec2::ONE).as_vec2();
+            let pixel = ((pos - area.min) / area.size() * max_pixel).clamp(Vec2::ZERO, max_pixel);
+            let (low, t) = (pixel.floor(), pixel.fract());
+            let high = (low + Vec2::ONE).min(max_pixel);
+            let sample = |x: f32, y: f32| heights[y as usize * size.x as usize + x as usize];
+            let top = sample(low.x, low.y).lerp(sample(high.x, low.y), t.x);
+            let bottom = sample(low.x, high.y).lerp(sample(high.x, high.y), t.x);
+            Some(min_height.lerp(max_height, top.lerp(bottom, t.y)))
+        })
+    }
+
+    /// Returns the height of the ground at the given world position (where X is world X and Y is
+    /// world Z), if there is ground there.
+    pub fn height_at(&self, pos: Vec2) -> Option<f32> {
+        (self.height)(pos)
+    }
+
+    /// Returns the first point on the ground hit by `ray`, if any.
+    fn cast_ray(&self, ray: Ray3d) -> Option<Vec3> {
+        // Straight down (or up) only needs a single sample
+        if ray.direction.xz() == Vec2::ZERO {
+            let height = self.height_at(ray.origin.xz())?;
+            let distance = (height - ray.origin.y) / ray.direction.y;
+            return (distance >= 0.0).then(|| ray.get_point(distance));
+        }
+        // Otherwise step along the ray until it's below the ground, then narrow down where it
+        // crossed between the last two steps
+        let below = |distance: f32| {
+            let point = ray.get_point(distance);
+            self.height_at(point.xz())
+                .is_some_and(|height| point.y <= height)
+        };
+        let step = self.ray_step.max(f32::EPSILON);
+        let mut above_distance = 0.0;
+        let mut distance = 0.0;
+        while distance <= self.ray_max_distance {
+            if below(distance) {
+                if distance == 0.0 {
+                    return Some(ray.origin);
+                }
+                let mut below_distance = distance;
+                for _ in 0..HEIGHTMAP_REFINE_ITERATIONS {
+                    let mid = (above_distance + below_distance) / 2.0;
+                    if below(mid) {
+                        below_distance = mid;
+                    } else {
+                        above_distance = mid;
+                    }
+                }
+                return Some(ray.get_point(below_distance));
+            }
+            above_distance = distance;
+            distance += step;
+        }
+        None
+    }
+}
+
+impl std::fmt::Debug for HeightmapGround {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HeightmapGround")
+            .field("ray_step", &self.ray_step)
+            .field("ray_max_distance", &self.ray_max_distance)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Resource that caches where each `RtsCamera` last found the ground, so the ground isn't ray cast
+/// again until the camera moves or the cache expires (see `RtsCamera.ground_recast_distance` and
+/// `RtsCamera.ground_refresh_interval`). If the terrain changes (e.g. it's deformed or a ground
+/// mesh is moved), call `force_ground_refresh` so that cameras pick up the new height straight away.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::RtsCameraGroundCache;
+/// fn on_crater_created(mut ground_cache: ResMut<RtsCameraGroundCache>) {
+///     ground_cache.force_ground_refresh();
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct RtsCameraGroundCache {
+    pub(crate) entries: EntityHashMap<GroundCacheEntry>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GroundCacheEntry {
+    /// The XZ of `target_focus` when the ground was found.
+    pub(crate) focus: Vec2,
+    /// Where the ground was found.
+    pub(crate) hit: Vec3,
+    /// When the ground was found (real time since startup).
+    pub(crate) time: Duration,
+}
+
+impl RtsCameraGroundCache {
+    /// Forgets where the ground is for all cameras, so it's found again on the next frame.
+    pub fn force_ground_refresh(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// A grid over the XZ plane of which `Ground` meshes are in each cell, so rays only need to be
+/// tested against the meshes they pass over, rather than every chunk of a large terrain.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct GroundIndex {
+    cells: HashMap<IVec2, Vec<Entity>>,
+    /// The cells each indexed mesh is in, as the first and last cell.
+    entries: EntityHashMap<IRect>,
+    /// Meshes too big to put in cells.
+    oversized: EntityHashSet,
+    /// Covers every cell that has ever had a mesh in it.
+    bounds: Option<IRect>,
+}
+
+impl GroundIndex {
+    fn insert(&mut self, entity: Entity, aabb: Aabb2d) {
+        self.remove(entity);
+        let cells = IRect::from_corners(
+            (aabb.min / GROUND_INDEX_CELL_SIZE).floor().as_ivec2(),
+            (aabb.max / GROUND_INDEX_CELL_SIZE).floor().as_ivec2(),
+        );
+        if cells.width() >= GROUND_INDEX_MAX_CELLS || cells.height() >= GROUND_INDEX_MAX_CELLS {
+            self.oversized.insert(entity);
+            return;
+        }
+        for x in cells.min.x..=cells.max.x {
+            for y in cells.min.y..=cells.max.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+        self.entries.insert(entity, cells);
+        self.bounds = Some(self.bounds.map_or(cells, |bounds| bounds.union(cells)));
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        self.oversized.remove(&entity);
+        let Some(cells) = self.entries.remove(&entity) else {
+            return;
+        };
+        for x in cells.min.x..=cells.max.x {
+            for y in cells.min.y..=cells.max.y {
+                let cell = IVec2::new(x, y);
+                let Some(entities) = self.cells.get_mut(&cell) else {
+                    continue;
+                };
+                entities.retain(|other| *other != entity);
+                if entities.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+
+    /// Returns every mesh that `rays` could hit, or `None` if a ray crosses too many cells to be
+    /// worth it.
+    fn candidates(&self, rays: &[Ray3d]) -> Option<EntityHashSet> {
+        let mut candidates = self.oversized.clone();
+        let Some(bounds) = self.bounds else {
+            return Some(candidates);
+        };
+        for ray in rays {
+            let mut steps = 0;
+            let completed = self.trace(*ray, bounds, |cell| {
+                steps += 1;
+                if let Some(entities) = self.cells.get(&cell) {
+                    candidates.extend(entities.iter().copied());
+                }
+                steps < GROUND_INDEX_MAX_STEPS
+            });
+            if !completed {
+                return None;
+            }
+        }
+        Some(candidates)
+    }
+
+    /// Calls `visit` with each cell within `bounds` that `ray` passes over, in order, until it
+    /// returns `false`. Returns whether every cell was visited.
+    fn trace(&self, ray: Ray3d, bounds: IRect, mut visit: impl FnMut(IVec2) -> bool) -> bool {
+        let origin = ray.origin.xz() / GROUND_INDEX_CELL_SIZE;
+        let dir = ray.direction.xz() / GROUND_INDEX_CELL_SIZE;
+        let area = Rect::from_corners(bounds.min.as_vec2(), (bounds.max + 1).as_vec2());
+        // Where the ray enters and leaves the bounds, in units of the ray's length
+        let (mut enter, mut exit) = (0.0, f32::INFINITY);
+        for axis in 0..2 {
+            if dir[axis] == 0.0 {
+                if origin[axis] < area.min[axis] || origin[axis] > area.max[axis] {
+                    return true;
+                }
+                continue;
+            }
+            let a = (area.min[axis] - origin[axis]) / dir[axis];
+            let b = (area.max[axis] - origin[axis]) / dir[axis];
+            enter = a.min(b).max(enter);
+            exit = a.max(b).min(exit);
+        }
+        if enter > exit {
+            return true;
+        }
+
+        // Step from cell to cell along the ray (Amanatides and Woo)
+        let start = origin + dir * enter;
+        let mut cell = start.floor().as_ivec2().clamp(bounds.min, bounds.max);
+        let step = dir.signum().as_ivec2();
+        let next_boundary = cell.as_vec2() + step.max(IVec2::ZERO).as_vec2();
+        let mut t_max = Vec2::select(
+            dir.cmpne(Vec2::ZERO),
+            (next_boundary - start) / dir,
+            Vec2::INFINITY,
+        );
+        let t_delta = Vec2::select(dir.cmpne(Vec2::ZERO), dir.recip().abs(), Vec2::INFINITY);
+        let length = exit - enter;
+        loop {
+            if !visit(cell) {
+                return false;
+            }
+            if t_max.x < t_max.y {
+                if t_max.x > length {
+                    return true;
+                }
+                cell.x += step.x;
+                t_max.x += t_delta.x;
+            } else {
+                if t_max.y > length || t_max.y.is_infinite() {
+                    return true;
+                }
+                cell.y += step.y;
+                t_max.y += t_delta.y;
+            }
+            if cell.cmplt(bounds.min).any() || cell.cmpgt(bounds.max).any() {
+                return true;
+            }
+        }
+    }
+}
+
+/// Component that puts `Ground` on specific ground layers, so different cameras can follow
+/// different ground, like `RenderLayers` does for rendering. Entities below it in the hierarchy
+/// are on the same layers, unless they have their own `GroundLayers`. Ground without this
+/// component is on layer `0`. A camera only follows ground on one of the layers in its
+/// `RtsCameraRayFilter` (see `RtsCameraRayFilter::with_ground_layers`), or layer `0` if it has no
+/// filter. Has no effect on `HeightmapGround`.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{Ground, GroundLayers, RtsCamera, RtsCameraRayFilter};
+/// fn setup(mut commands: Commands) {
+///     // Only followed by the second camera
+///     commands.spawn((Ground, GroundLayers::layer(1)));
+///     commands.spawn(RtsCamera::default());
+///     commands.spawn((
+///         RtsCamera::default(),
+///         RtsCameraRayFilter::new(|_| true).with_ground_layers(GroundLayers::layer(1)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct GroundLayers(pub u32);
+
+impl GroundLayers {
+    /// Every layer.
+    pub const ALL: Self = Self(u32::MAX);
+    /// No layers.
+    pub const NONE: Self = Self(0);
+
+    /// Creates a `GroundLayers` with only `layer` (from `0` to `31`).
+    pub const fn layer(layer: u8) -> Self {
+        Self(1 << layer)
+    }
+
+    /// Adds `layer` (from `0` to `31`).
+    pub const fn with(self, layer: u8) -> Self {
+        Self(self.0 | 1 << layer)
+    }
+
+    /// Returns whether any layer is in both `self` and `other`.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl Default for GroundLayers {
+    fn default() -> Self {
+        Self::layer(0)
+    }
+}
+
+/// Marks the entities that ground ray casts can hit: those with `Ground`, and everything below
+/// them in the hierarchy (except `NotGround`). `Ground` is often added to the root of a scene,
+/// while the meshes that rays hit are its children.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq)]
+pub(crate) struct GroundMesh {
+    /// The layers the mesh is on, from its own `GroundLayers` or its closest ancestor's.
+    layers: GroundLayers,
+}
+
+impl GroundMesh {
+    /// Returns whether a ray cast for a camera with `filter` can hit `entity`, which this is on.
+    pub(crate) fn accepts(&self, entity: Entity, filter: Option<&RtsCameraRayFilter>) -> bool {
+        let layers = filter.map_or_else(GroundLayers::default, |filter| filter.ground_layers);
+        self.layers.intersects(layers) && filter.is_none_or(|filter| filter.test(entity))
+    }
+}
+
+/// Entities whose ground status may have changed, along with everything below them.
+type GroundDirty = Or<(
+    Added<Ground>,
+    Added<NotGround>,
+    Changed<GroundLayers>,
+    Changed<Parent>,
+)>;
+
+/// Keeps `GroundMesh` on exactly the entities that are, or are below, a `Ground` entity, and
+/// aren't excluded with `NotGround`.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn propagate_ground_mesh(
+    changed_q: Query<Entity, GroundDirty>,
+    mut removed_ground: RemovedComponents<Ground>,
+    mut removed_not_ground: RemovedComponents<NotGround>,
+    mut removed_layers: RemovedComponents<GroundLayers>,
+    mut removed_parent: RemovedComponents<Parent>,
+    node_q: Query<(
+        Has<Ground>,
+        Has<NotGround>,
+        Option<&GroundLayers>,
+        Option<&GroundMesh>,
+        Option<&Children>,
+    )>,
+    parent_q: Query<&Parent>,
+    mut commands: Commands,
+) {
+    let mut dirty = changed_q
+        .iter()
+        .chain(removed_ground.read())
+        .chain(removed_not_ground.read())
+        .chain(removed_layers.read())
+        .chain(removed_parent.read())
+        .filter(|entity| node_q.contains(*entity))
+        .map(|entity| (parent_q.iter_ancestors(entity).count(), entity))
+        .collect::<Vec<_>>();
+    // Parents first, so their children are updated along with them, using their new state
+    dirty.sort_unstable();
+    let mut visited = EntityHashSet::default();
+    for (_, root) in dirty {
+        if visited.contains(&root) {
+            continue;
+        }
+        let inherited = parent_q
+            .get(root)
+            .ok()
+            .and_then(|parent| node_q.get(parent.get()).ok())
+            .and_then(|(.., mesh, _)| mesh.copied());
+        let mut stack = vec![(root, inherited)];
+        while let Some((entity, inherited)) = stack.pop() {
+            if !visited.insert(entity) {
+                continue;
+            }
+            let Ok((ground, not_ground, layers, mesh, children)) = node_q.get(entity) else {
+                continue;
+            };
+            let new_mesh = (!not_ground && (ground || inherited.is_some())).then(|| GroundMesh {
+                layers: layers
+                    .copied()
+                    .or(inherited.map(|mesh| mesh.layers))
+                    .unwrap_or_default(),
+            });
+            match new_mesh {
+                Some(new_mesh) if mesh != Some(&new_mesh) => {
+                    commands.entity(entity).insert(new_mesh);
+                }
+                None if mesh.is_some() => {
+                    commands.entity(entity).remove::<GroundMesh>();
+                }
+                _ => {}
+            }
+            if let Some(children) = children {
+                stack.extend(children.iter().map(|child| (*child, new_mesh)));
+            }
+        }
+    }
+}
+
+/// Ground meshes that need to be (re)inserted into the `GroundIndex`.
+type ChangedGround = (
+    With<GroundMesh>,
+    Or<(Added<GroundMesh>, Changed<Aabb>, Changed<GlobalTransform>)>,
+);
+
+pub(crate) fn update_ground_index(
+    index: Option<ResMut<GroundIndex>>,
+    ground_q: Query<(Entity, &Aabb, &GlobalTransform), ChangedGround>,
+    mut removed: RemovedComponents<GroundMesh>,
+) {
+    // Every ground mesh is tested by every ray without the index, e.g. after
+    // `RtsCameraTestApp::remove_ground_index`
+    let Some(mut index) = index else {
+        return;
+    };
+    for entity in removed.read() {
+        index.remove(entity);
+    }
+    for (entity, aabb, tfm) in ground_q.iter() {
+        // The world space bounds of the (possibly rotated) local AABB
+        let affine = tfm.affine();
+        let center = affine.transform_point3a(aabb.center);
+        let half_extents = affine.matrix3.x_axis.abs() * aabb.half_extents.x
+            + affine.matrix3.y_axis.abs() * aabb.half_extents.y
+            + affine.matrix3.z_axis.abs() * aabb.half_extents.z;
+        index.insert(
+            entity,
+            Aabb2d {
+                min: (center - half_extents).xz(),
+                max: (center + half_extents).xz(),
+            },
+        );
+    }
+}
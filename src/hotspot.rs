@@ -0,0 +1,137 @@
+use crate::RtsCameraSystemSet;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// How many hotspots `RtsCameraHotspots` remembers by default.
+const DEFAULT_HOTSPOT_CAPACITY: usize = 8;
+
+pub struct RtsCameraHotspotPlugin;
+
+impl Plugin for RtsCameraHotspotPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CameraHotspot>()
+            .init_resource::<RtsCameraHotspots>()
+            .add_systems(Update, record_hotspots.before(RtsCameraSystemSet));
+    }
+}
+
+/// Event that registers a point of interest, such as where a unit is under attack or a building
+/// just finished, which the player can jump the camera to with
+/// `RtsCameraControls.key_jump_to_hotspot` (like "jump to last alert"). Hotspots are stored in
+/// `RtsCameraHotspots`.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::CameraHotspot;
+/// fn unit_attacked(mut hotspots: EventWriter<CameraHotspot>) {
+///     hotspots.send(CameraHotspot::new(Vec3::new(10.0, 0.0, -5.0)).with_priority(1));
+/// }
+/// ```
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CameraHotspot {
+    /// Where the point of interest is. The height is replaced by the height of the ground when
+    /// jumping to it.
+    pub position: Vec3,
+    /// Hotspots with a higher priority are jumped to before those with a lower priority, however
+    /// recent, and are the last to be forgotten once `RtsCameraHotspots` is full.
+    pub priority: i32,
+}
+
+impl CameraHotspot {
+    /// Creates a hotspot at `position` with a priority of `0`.
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            position,
+            priority: 0,
+        }
+    }
+
+    /// Sets the priority of the hotspot.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Resource that stores the most recent `CameraHotspot`s. Jumping to a hotspot (with
+/// `RtsCameraControls.key_jump_to_hotspot` or `next_hotspot`) picks the most recent one with the
+/// highest priority, and repeated jumps cycle through the rest, from highest priority and most
+/// recent to lowest priority and oldest. Adding a hotspot starts the cycle again.
+#[derive(Resource, Debug)]
+pub struct RtsCameraHotspots {
+    // Most recent first
+    hotspots: VecDeque<CameraHotspot>,
+    capacity: usize,
+    next: usize,
+}
+
+impl Default for RtsCameraHotspots {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_HOTSPOT_CAPACITY)
+    }
+}
+
+impl RtsCameraHotspots {
+    /// Creates an empty set of hotspots that remembers up to `capacity` hotspots. The default
+    /// capacity is `8`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            hotspots: VecDeque::with_capacity(capacity + 1),
+            capacity,
+            next: 0,
+        }
+    }
+
+    /// Adds a hotspot, forgetting the oldest of the lowest priority hotspots if there are more
+    /// than the capacity.
+    pub fn push(&mut self, hotspot: CameraHotspot) {
+        self.hotspots.push_front(hotspot);
+        if self.hotspots.len() > self.capacity {
+            let lowest = self
+                .hotspots
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, hotspot)| hotspot.priority)
+                .map(|(i, _)| i);
+            if let Some(lowest) = lowest {
+                self.hotspots.remove(lowest);
+            }
+        }
+        self.next = 0;
+    }
+
+    /// Returns the hotspot to jump to next and moves on to the one after it, or `None` if there
+    /// are no hotspots.
+    pub fn next_hotspot(&mut self) -> Option<CameraHotspot> {
+        let hotspot = self
+            .iter()
+            .nth(self.next % self.hotspots.len().max(1))
+            .copied();
+        self.next = self.next.wrapping_add(1);
+        hotspot
+    }
+
+    /// Returns the hotspots in the order they are jumped to.
+    pub fn iter(&self) -> impl Iterator<Item = &CameraHotspot> {
+        let mut hotspots = self.hotspots.iter().collect::<Vec<_>>();
+        // Stable, so the most recent is still first out of hotspots with the same priority
+        hotspots.sort_by_key(|hotspot| std::cmp::Reverse(hotspot.priority));
+        hotspots.into_iter()
+    }
+
+    /// Forgets all hotspots.
+    pub fn clear(&mut self) {
+        self.hotspots.clear();
+        self.next = 0;
+    }
+}
+
+fn record_hotspots(
+    mut events: EventReader<CameraHotspot>,
+    mut hotspots: ResMut<RtsCameraHotspots>,
+) {
+    for event in events.read() {
+        hotspots.push(*event);
+    }
+}
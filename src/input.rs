@@ -0,0 +1,134 @@
+use crate::ext::grab_pan_offset;
+#[cfg(feature = "controller")]
+use crate::RtsCameraControls;
+use crate::{RtsCamera, RtsCameraExt};
+use bevy::prelude::*;
+
+/// Event that drives an `RtsCamera`, as an alternative to `RtsCameraControls`. Useful for input
+/// managers, networked or scripted input, or anything else that wants to control the camera
+/// without mutating `RtsCamera` directly. Works whether or not the camera has
+/// `RtsCameraControls`, and is applied before `RtsCameraSystemSet`.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCameraInput, RtsCameraInputKind};
+/// fn zoom_in(mut events: EventWriter<RtsCameraInput>) {
+///     events.send(RtsCameraInput::all(RtsCameraInputKind::Zoom(0.1)));
+/// }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct RtsCameraInput {
+    /// The camera entity this input is for, or `None` for all cameras.
+    pub camera: Option<Entity>,
+    /// What the input should do.
+    pub kind: RtsCameraInputKind,
+}
+
+impl RtsCameraInput {
+    /// Creates an input for a specific camera.
+    pub fn new(camera: Entity, kind: RtsCameraInputKind) -> Self {
+        Self {
+            camera: Some(camera),
+            kind,
+        }
+    }
+
+    /// Creates an input for all cameras.
+    pub fn all(kind: RtsCameraInputKind) -> Self {
+        Self { camera: None, kind }
+    }
+}
+
+/// The different kinds of `RtsCameraInput`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RtsCameraInputKind {
+    /// Moves the target focus by this many world units, where X is right and Y is forward relative
+    /// to the camera's orientation. If the camera has `RtsCameraControls`, this is scaled by its
+    /// `pan_speed_zoom_factor`.
+    Pan(Vec2),
+    /// Changes the target zoom by this amount (e.g. `0.1` zooms in by 10% of the zoom range). If
+    /// the camera has `RtsCameraControls`, this is reversed by its `invert_zoom`.
+    Zoom(f32),
+    /// Rotates the target focus by this many radians around the Y axis. Positive values rotate
+    /// counterclockwise when looking down. If the camera has `RtsCameraControls`, this is scaled
+    /// by its `rotate_sensitivity` and reversed by its `invert_rotate`.
+    Rotate(f32),
+    /// Tilts the camera by this many radians (see `RtsCamera::tilt`). Positive values tilt it up
+    /// towards the horizon.
+    Tilt(f32),
+    /// Moves the target focus the same way as dragging the mouse by this many logical pixels while
+    /// holding `RtsCameraControls.button_drag`, including its `drag_sensitivity`, `invert_drag_x`
+    /// and `invert_drag_y`.
+    Grab {
+        /// How far the cursor moved, in logical pixels.
+        delta: Vec2,
+    },
+    /// Moves the target focus to this position. The camera will smoothly move there.
+    JumpTo(Vec3),
+}
+
+pub(crate) fn handle_input_events(
+    mut events: EventReader<RtsCameraInput>,
+    mut cam_q: Query<(Entity, &Transform, &mut RtsCamera, &Camera, &Projection)>,
+    #[cfg(feature = "controller")] controls_q: Query<&RtsCameraControls>,
+) {
+    for event in events.read() {
+        for (entity, cam_tfm, mut cam, camera, projection) in cam_q.iter_mut() {
+            if event.camera.is_some_and(|target| target != entity) {
+                continue;
+            }
+            #[cfg(feature = "controller")]
+            let kind = adjust_input(event.kind, controls_q.get(entity).ok(), cam.target_zoom);
+            #[cfg(not(feature = "controller"))]
+            let kind = event.kind;
+            match kind {
+                RtsCameraInputKind::Pan(delta) => {
+                    let delta =
+                        cam.target_focus.right() * delta.x + cam.target_focus.forward() * delta.y;
+                    cam.pan_world(delta);
+                }
+                RtsCameraInputKind::Zoom(delta) => {
+                    cam.zoom_by(delta);
+                }
+                RtsCameraInputKind::Rotate(angle) => {
+                    cam.orbit_yaw(angle);
+                }
+                RtsCameraInputKind::Tilt(angle) => {
+                    cam.tilt(angle);
+                }
+                RtsCameraInputKind::Grab { delta } => {
+                    let offset = grab_pan_offset(cam_tfm, &cam, camera, projection, None, delta);
+                    cam.target_focus.translation += offset;
+                }
+                RtsCameraInputKind::JumpTo(target) => {
+                    cam.jump_to(target);
+                }
+            }
+        }
+    }
+}
+
+/// Applies the speed, sensitivity and inversion settings of a camera's `controls` to an input.
+#[cfg(feature = "controller")]
+fn adjust_input(
+    kind: RtsCameraInputKind,
+    controls: Option<&RtsCameraControls>,
+    zoom: f32,
+) -> RtsCameraInputKind {
+    let Some(controls) = controls else {
+        return kind;
+    };
+    match kind {
+        RtsCameraInputKind::Pan(delta) => {
+            RtsCameraInputKind::Pan(delta * controls.pan_speed_factor(zoom))
+        }
+        RtsCameraInputKind::Zoom(delta) => RtsCameraInputKind::Zoom(controls.zoom_input(delta)),
+        RtsCameraInputKind::Rotate(angle) => {
+            RtsCameraInputKind::Rotate(controls.rotate_input(angle))
+        }
+        RtsCameraInputKind::Grab { delta } => RtsCameraInputKind::Grab {
+            delta: controls.drag_input(delta),
+        },
+        RtsCameraInputKind::Tilt(_) | RtsCameraInputKind::JumpTo(_) => kind,
+    }
+}
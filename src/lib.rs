@@ -2,20 +2,110 @@
 #![doc = include_str!("../README.md")]
 
 use std::f32::consts::TAU;
+use std::sync::Arc;
+use std::time::Duration;
 
-use bevy::math::bounding::Aabb2d;
-use bevy::picking::mesh_picking::ray_cast::RayMeshHit;
+use bevy::app::RunFixedMainLoopSystem;
+use bevy::ecs::entity::{EntityHashMap, EntityHashSet};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::SystemParam;
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
 use bevy::prelude::*;
+use bevy::render::camera::CameraUpdateSystem;
 
-pub use controller::RtsCameraControls;
+pub use auto_frame::{CameraFramed, RtsCameraAutoFrame};
+#[cfg(feature = "avian3d")]
+pub use avian_ground::AvianGround;
+#[cfg(feature = "controller")]
+pub use binding::{ControlBinding, RebindError, RtsCameraAction};
+pub use bookmark::CameraBookmarks;
+pub use bounds::{CameraBounds, CameraBoundsMode};
+#[cfg(feature = "controller")]
+pub use controller::{
+    rts_camera_input_enabled, DiagonalPan, EdgePanWidths, RtsCameraControls, RtsCameraInputEnabled,
+    RtsCameraInputSuppression, RtsCameraPointerBlocked, RtsCameraTouchControls, ZoomMode,
+};
+pub use director::{RtsCameraDirector, RtsCameraDirectorCommand};
+pub use ext::RtsCameraExt;
+#[cfg(feature = "gltf_tagging")]
+pub use gltf_tagging::GroundTagConfig;
+pub use ground::{
+    GroundBackend, GroundLayers, HeightmapGround, MeshGround, RtsCameraGround, RtsCameraGroundCache,
+};
+pub use hotspot::{CameraHotspot, RtsCameraHotspots};
+pub use input::{RtsCameraInput, RtsCameraInputKind};
+pub use listener::{ListenerPlacement, RtsCameraListener};
+pub use path::{CameraPath, CameraPathFinished, CameraPathKeyframe, CameraPathKeyframeReached};
+pub use profile::{RtsCameraProfile, RtsCameraProfiles};
+#[cfg(feature = "bevy_rapier3d")]
+pub use rapier_ground::RapierGround;
+#[cfg(feature = "settings_asset")]
+pub use settings::{RtsCameraSettings, RtsCameraSettingsHandle, RtsCameraSettingsLoaderError};
+pub use transition::{CameraTransition, CameraTransitionFinished};
+pub use zoom_constraint::ZoomConstraintVolume;
+pub use zoom_driven::ZoomDriven;
 
+use crate::auto_frame::auto_frame;
+#[cfg(feature = "controller")]
 use crate::controller::RtsCameraControlsPlugin;
+use crate::director::run_director;
+use crate::ground::{propagate_ground_mesh, update_ground_index, GroundCacheEntry, GroundIndex};
+use crate::hotspot::RtsCameraHotspotPlugin;
+use crate::input::handle_input_events;
+use crate::listener::place_listeners;
+use crate::path::play_camera_paths;
+use crate::profile::RtsCameraProfilePlugin;
+use crate::ray_cast::BatchRayCast;
+use crate::transition::blend_transitions;
+use crate::zoom_constraint::apply_zoom_constraints;
+use crate::zoom_driven::apply_zoom_driven;
 
+mod auto_frame;
+#[cfg(feature = "avian3d")]
+mod avian_ground;
+#[cfg(feature = "controller")]
+mod binding;
+mod bookmark;
+mod bounds;
+#[cfg(feature = "controller")]
 mod controller;
+mod director;
+mod ext;
+#[cfg(feature = "gltf_tagging")]
+mod gltf_tagging;
+mod ground;
+mod hotspot;
+mod input;
+mod listener;
+mod path;
+mod profile;
+#[cfg(feature = "bevy_rapier3d")]
+mod rapier_ground;
+mod ray_cast;
+#[cfg(feature = "settings_asset")]
+mod settings;
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_utils;
+mod transition;
+mod zoom_constraint;
+mod zoom_driven;
 
+/// The default `RtsCamera.max_angle`.
 const MAX_ANGLE: f32 = TAU / 5.0;
+/// How close the camera needs to be to its targets before smoothing stops and it snaps to them.
+const SETTLE_THRESHOLD: f32 = 1e-4;
+/// How far the up of the focus can be from the normal of `RtsCamera.ground_plane` before it's
+/// straightened.
+const UPRIGHT_TOLERANCE: f32 = 1e-4;
+
+/// How many times `RtsCamera::zoom_at_height` halves the search range, which is enough for
+/// `f32` precision.
+const ZOOM_SEARCH_ITERATIONS: usize = 24;
 
 /// Bevy plugin that provides RTS camera controls.
+/// Doesn't need a window, input, or rendering, so the camera can also be simulated headlessly
+/// (e.g. with `MinimalPlugins`) for integration tests or server-side replay rendering. The
+/// controller simply does nothing without a window and `InputPlugin`.
 /// # Example
 /// ```no_run
 /// # use bevy::prelude::*;
@@ -23,40 +113,383 @@ const MAX_ANGLE: f32 = TAU / 5.0;
 /// fn main() {
 ///     App::new()
 ///         .add_plugins(DefaultPlugins)
-///         .add_plugins(RtsCameraPlugin)
+///         .add_plugins(RtsCameraPlugin::default())
 ///         .run();
 /// }
 /// ```
-pub struct RtsCameraPlugin;
+/// Headless, e.g. in an integration test:
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, RtsCameraPlugin, RtsCameraSnap};
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, RtsCameraPlugin::default()));
+/// let cam = app
+///     .world_mut()
+///     .spawn(RtsCamera {
+///         target_zoom: 1.0,
+///         snap: RtsCameraSnap::ALL,
+///         ..default()
+///     })
+///     .id();
+/// app.update();
+/// assert_eq!(app.world().get::<RtsCamera>(cam).unwrap().zoom, 1.0);
+/// ```
+pub struct RtsCameraPlugin {
+    focus_constraints: Vec<SystemRegistration>,
+    zoom_driven: Vec<SystemRegistration>,
+    ground_backend: fn(&mut App, InternedScheduleLabel),
+    schedule: InternedScheduleLabel,
+}
+
+/// Adds a system registered with the `RtsCameraPlugin` builder to the app, in the schedule that
+/// `RtsCameraSystemSet` runs in.
+type SystemRegistration = Box<dyn Fn(&mut App, InternedScheduleLabel) + Send + Sync>;
+
+impl Default for RtsCameraPlugin {
+    fn default() -> Self {
+        Self {
+            focus_constraints: Vec::new(),
+            zoom_driven: Vec::new(),
+            ground_backend: add_ground_systems::<MeshGround>,
+            schedule: PostUpdate.intern(),
+        }
+        .with_zoom_driven::<Projection>()
+    }
+}
+
+impl RtsCameraPlugin {
+    /// Creates the plugin with its default configuration, the same as `RtsCameraPlugin::default()`,
+    /// to be customised with the `with_*` methods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `RtsCameraSystemSet` in `schedule` instead of `PostUpdate`. Input from
+    /// `RtsCameraControls` is still read every frame in `Update`.
+    /// In `PostUpdate`, the camera's `Transform` is written just before transform propagation, so
+    /// systems in `Update` that read its `GlobalTransform` (e.g. to project a selection box)
+    /// always see the transform of the frame on screen. In `Update`, systems that run after
+    /// `RtsCameraSystemSet` see the camera's new focus and zoom in the same frame, but its
+    /// `GlobalTransform` is out of date with its `Transform` until propagation runs.
+    /// When `schedule` is one of the fixed timestep schedules (e.g. `FixedUpdate`), smoothing
+    /// uses `Time<Fixed>` instead of `Time<Real>`, and the camera's `Transform` is interpolated
+    /// between the last two fixed steps every frame, so it moves smoothly along with the rest of
+    /// an interpolated world. Note that this means the camera stops when virtual time is paused.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::RtsCameraPlugin;
+    /// # fn main() {
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugins(RtsCameraPlugin::default().with_schedule(FixedUpdate))
+    ///     .run();
+    /// # }
+    /// ```
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    fn is_fixed_timestep(&self) -> bool {
+        [
+            FixedFirst.intern(),
+            FixedPreUpdate.intern(),
+            FixedUpdate.intern(),
+            FixedPostUpdate.intern(),
+            FixedLast.intern(),
+        ]
+        .contains(&self.schedule)
+    }
+
+    /// Registers a system that constrains where the camera can go, such as keeping it over a
+    /// navmesh or over territory the player owns. The system runs every frame after `bounds` have
+    /// been applied but before smoothing, and should modify `RtsCamera.target_focus` if the
+    /// proposed focus is not allowed. Can be called multiple times to register multiple
+    /// constraints, which will run in the order they were registered. The system must be `Clone`
+    /// (as functions and most closures are), so the plugin can be built more than once.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::{RtsCameraPlugin, RtsCamera};
+    /// # fn main() {
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugins(RtsCameraPlugin::default().with_focus_constraint(stay_north))
+    ///     .run();
+    /// # }
+    /// // Don't allow the camera to go south of the world origin
+    /// fn stay_north(mut cam_q: Query<&mut RtsCamera>) {
+    ///     for mut cam in cam_q.iter_mut() {
+    ///         cam.target_focus.translation.z = cam.target_focus.translation.z.min(0.0);
+    ///     }
+    /// }
+    /// ```
+    pub fn with_focus_constraint<M: 'static>(
+        mut self,
+        system: impl IntoSystemConfigs<M> + Clone + Send + Sync + 'static,
+    ) -> Self {
+        let set = RtsCameraFocusConstraintSet(self.focus_constraints.len());
+        self.focus_constraints.push(Box::new(move |app, schedule| {
+            app.add_systems(schedule, system.clone().in_set(set));
+        }));
+        self
+    }
+
+    /// Allows `ZoomDriven<C>` to update components of type `C`. `Projection` is already
+    /// registered.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::RtsCameraPlugin;
+    /// # #[derive(Component)]
+    /// # struct LevelOfDetail { bias: f32 }
+    /// # fn main() {
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugins(RtsCameraPlugin::default().with_zoom_driven::<LevelOfDetail>())
+    ///     .run();
+    /// # }
+    /// ```
+    pub fn with_zoom_driven<C: Component>(mut self) -> Self {
+        self.zoom_driven.push(Box::new(|app, schedule| {
+            app.add_systems(
+                schedule,
+                apply_zoom_driven::<C>.in_set(RtsCameraPhase::PostTransform),
+            );
+        }));
+        self
+    }
+
+    /// Finds the ground with `B` instead of ray casting against meshes marked with `Ground`, e.g.
+    /// to use a physics engine's colliders. See `GroundBackend`.
+    pub fn with_ground_backend<B: GroundBackend>(mut self) -> Self {
+        self.ground_backend = add_ground_systems::<B>;
+        self
+    }
+}
+
+/// Adds the systems that need the ground, using `B` to find it.
+fn add_ground_systems<B: GroundBackend>(app: &mut App, schedule: InternedScheduleLabel) {
+    #[cfg(feature = "controller")]
+    app.add_plugins(RtsCameraControlsPlugin::<B>::default());
+    app.add_systems(
+        schedule,
+        (
+            follow_ground::<B>
+                .after(jump_to)
+                .before(finish_initialize)
+                .in_set(RtsCameraPhase::GroundFollow),
+            keep_ground_clearance::<B>
+                .after(move_towards_target)
+                .in_set(RtsCameraPhase::Smoothing),
+            avoid_ground_clipping::<B>
+                .after(update_camera_transform)
+                .before(avoid_obstacles)
+                .in_set(RtsCameraPhase::ApplyTransform),
+        ),
+    );
+}
 
 impl Plugin for RtsCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(RtsCameraControlsPlugin)
-            .add_systems(PreUpdate, initialize)
+        let schedule = self.schedule;
+        for (i, register) in self.focus_constraints.iter().enumerate() {
+            app.configure_sets(
+                schedule,
+                RtsCameraFocusConstraintSet(i)
+                    .after(apply_bounds)
+                    .before(snap_to_target)
+                    .in_set(RtsCameraPhase::Bounds),
+            );
+            if i > 0 {
+                app.configure_sets(
+                    schedule,
+                    RtsCameraFocusConstraintSet(i).after(RtsCameraFocusConstraintSet(i - 1)),
+                );
+            }
+            register(app, schedule);
+        }
+        for register in &self.zoom_driven {
+            register(app, schedule);
+        }
+        (self.ground_backend)(app, schedule);
+        #[cfg(feature = "settings_asset")]
+        app.add_plugins(settings::RtsCameraSettingsPlugin);
+        #[cfg(feature = "gltf_tagging")]
+        app.add_plugins(gltf_tagging::GroundTaggingPlugin);
+        app.add_plugins((RtsCameraProfilePlugin, RtsCameraHotspotPlugin))
+            .add_event::<RtsCameraInput>()
+            .add_event::<CameraMoveComplete>()
+            .add_event::<RtsCameraResetComplete>()
+            .add_event::<RtsCameraMoved>()
+            .add_event::<RtsCameraZoomChanged>()
+            .add_event::<RtsCameraRotated>()
+            .add_event::<CameraHeadingChanged>()
+            .add_event::<RtsCameraHitBounds>()
+            .add_event::<RtsCameraGroundFallback>()
+            .add_event::<RtsCameraOccluded>()
+            .add_event::<RtsCameraJumpTo>()
+            .add_event::<CameraPathKeyframeReached>()
+            .add_event::<CameraPathFinished>()
+            .add_event::<CameraTransitionFinished>()
+            .init_resource::<RtsCameraGroundCache>()
+            .init_resource::<GroundIndex>()
+            .init_resource::<CameraBookmarks>()
+            .init_resource::<RtsCameraDirector>()
+            .insert_resource(CameraTimestep {
+                fixed: self.is_fixed_timestep(),
+            })
             .add_systems(
-                Update,
+                PreUpdate,
+                (propagate_ground_mesh, update_ground_index).chain(),
+            )
+            .add_systems(Update, handle_input_events.before(RtsCameraSystemSet))
+            .configure_sets(
+                schedule,
                 (
-                    follow_ground,
-                    snap_to_target,
-                    dynamic_angle,
-                    move_towards_target,
-                    apply_bounds,
-                    update_camera_transform,
+                    RtsCameraPhase::GroundFollow,
+                    RtsCameraPhase::TargetResolution,
+                    RtsCameraPhase::Smoothing,
+                    RtsCameraPhase::ApplyTransform,
+                    RtsCameraPhase::PostTransform,
                 )
                     .chain()
                     .in_set(RtsCameraSystemSet),
+            )
+            .configure_sets(
+                schedule,
+                RtsCameraPhase::Bounds.in_set(RtsCameraPhase::TargetResolution),
+            )
+            // Only has an effect when running in `PostUpdate`
+            .configure_sets(
+                schedule,
+                RtsCameraSystemSet
+                    .before(TransformSystem::TransformPropagate)
+                    .before(CameraUpdateSystem),
+            )
+            .add_systems(
+                schedule,
+                (
+                    (
+                        // Snaps cameras added this frame before anything moves them, in whichever
+                        // schedule the camera runs in
+                        initialize,
+                        run_director,
+                        follow_entity,
+                        auto_frame,
+                        jump_to,
+                        finish_initialize,
+                    )
+                        .chain()
+                        .in_set(RtsCameraPhase::GroundFollow),
+                    (
+                        fly,
+                        keep_upright,
+                        apply_bounds.in_set(RtsCameraPhase::Bounds),
+                        dynamic_angle,
+                        apply_zoom_constraints,
+                        play_camera_paths,
+                        snap_to_target,
+                    )
+                        .chain()
+                        .in_set(RtsCameraPhase::TargetResolution),
+                    move_towards_target.in_set(RtsCameraPhase::Smoothing),
+                    (
+                        update_camera_transform,
+                        avoid_obstacles,
+                        blend_transitions,
+                        mark_occluders,
+                    )
+                        .chain()
+                        .in_set(RtsCameraPhase::ApplyTransform),
+                    (
+                        update_frustum_footprint,
+                        send_change_events,
+                        finish_reset,
+                        place_listeners,
+                    )
+                        .in_set(RtsCameraPhase::PostTransform),
+                ),
             );
+        if self.is_fixed_timestep() {
+            app.init_resource::<CameraInterpolation>()
+                .add_systems(schedule, record_fixed_transform.after(RtsCameraSystemSet))
+                .add_systems(
+                    RunFixedMainLoop,
+                    interpolate_transform.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+                );
+        }
     }
 }
 
 /// System set containing all the systems that control the RTS camera.
 /// If you want to control the camera manually in any way (e.g. snapping to a specific location),
 /// you should run that before this system set.
+/// Runs in `PostUpdate`, unless changed with `RtsCameraPlugin::with_schedule`.
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct RtsCameraSystemSet;
 
+/// Contains a single focus constraint registered with `RtsCameraPlugin::with_focus_constraint`,
+/// identified by the order it was registered in.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+struct RtsCameraFocusConstraintSet(usize);
+
+/// The phases that make up `RtsCameraSystemSet`, which run in the order they are declared, apart
+/// from `Bounds`, which is part of `TargetResolution`.
+/// Use these if you need to run a system in between two phases, for example to clamp
+/// `RtsCamera.target_focus` after it has been moved to the ground but before it's smoothed.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCameraPlugin, RtsCameraPhase};
+/// # fn main() {
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(RtsCameraPlugin::default())
+///     .add_systems(
+///         PostUpdate,
+///         my_clamp
+///             .after(RtsCameraPhase::GroundFollow)
+///             .before(RtsCameraPhase::TargetResolution),
+///     )
+///     .run();
+/// # }
+/// # fn my_clamp() {}
+/// ```
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum RtsCameraPhase {
+    /// Moves `RtsCamera.target_focus` to the entity in `RtsCameraFollow` (if any), then vertically
+    /// so it sits on the ground.
+    GroundFollow,
+    /// Finalises the targets for this frame: advances `RtsCamera::fly_to` moves, applies bounds,
+    /// updates `RtsCamera.target_angle` if
+    /// `dynamic_angle` is enabled, and handles `RtsCamera.snap`.
+    TargetResolution,
+    /// The part of `TargetResolution` that keeps `RtsCamera.target_focus` within
+    /// `RtsCamera.bounds` and applies focus constraints (see
+    /// `RtsCameraPlugin::with_focus_constraint`). Run systems after this and before
+    /// `ApplyTransform` to react to where the camera is going this frame, e.g. to reveal fog of
+    /// war.
+    Bounds,
+    /// Moves the current focus, zoom, and angle towards their targets.
+    Smoothing,
+    /// Writes the camera's `Transform` based on the current focus, zoom, and angle, then pulls it
+    /// in towards the focus if it would otherwise be too close to a `CameraObstacle`, and blends
+    /// it with any `CameraTransition`.
+    ApplyTransform,
+    /// Phase that runs after the camera's `Transform` has been written, but before
+    /// transform propagation and rendering. Add systems here to make final adjustments to the
+    /// `Transform` (e.g. screen shake or small offsets) without them being overwritten.
+    /// Note that the `Transform` is recalculated from scratch each frame, so any changes made here
+    /// only last for a single frame.
+    PostTransform,
+}
+
 /// Marks a camera to be used as an RTS camera.
-/// Only one instance of this component should exist at any given moment.
+/// Multiple RTS cameras can exist at once (e.g. for split-screen, or across several windows).
+/// Each one is controlled independently, and mouse input (zoom, rotate, and drag) goes to the
+/// camera whose viewport the cursor is over.
 /// This does not include a controller. Add `RtsCameraControls` as well if you want.
 /// # Example
 /// ```no_run
@@ -65,7 +498,7 @@ pub struct RtsCameraSystemSet;
 /// # fn main() {
 /// #     App::new()
 /// #         .add_plugins(DefaultPlugins)
-/// #         .add_plugins(RtsCameraPlugin)
+/// #         .add_plugins(RtsCameraPlugin::default())
 /// #         .add_systems(Startup, setup)
 /// #         .run();
 /// # }
@@ -76,7 +509,8 @@ pub struct RtsCameraSystemSet;
 ///         ));
 ///  }
 /// ```
-#[derive(Component, Copy, Clone, Debug)]
+#[derive(Component, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[require(Camera3d)]
 pub struct RtsCamera {
     /// The minimum height the camera can zoom in to, or the height of the camera at `1.0` zoom.
@@ -87,11 +521,24 @@ pub struct RtsCamera {
     /// Defaults to `10.0`.
     pub height_max: f32,
     /// The bounds in which the camera is constrained, along the XZ plane of `target_focus`. This
-    /// prevents panning past these limits. Imagine looking directly down relative to `target_focus`
-    /// and the XZ plane corresponds XY of the Vec2s, except +Y is up/forward (-Z).
-    /// Defaults to `Aabb2d::new(Vec2::ZERO, Vec2::new(20.0, 20.0))` (i.e. can move 20.0 in any
-    /// direction starting at world center).
-    pub bounds: Aabb2d,
+    /// prevents panning past these limits. Can be a rectangle, circle, or convex polygon. See
+    /// `CameraBounds` for the coordinate system.
+    /// Defaults to `CameraBounds::Aabb(Aabb2d::new(Vec2::ZERO, Vec2::new(20.0, 20.0)))` (i.e. can
+    /// move 20.0 in any direction starting at world center).
+    pub bounds: CameraBounds,
+    /// Which part of the camera is kept within `bounds`. Bounds are always in world space, so with
+    /// `CameraBoundsMode::Focus` the area the camera can see past the edge of the bounds changes as
+    /// it rotates. Use `CameraBoundsMode::View` to keep the area the camera is looking over within
+    /// the bounds instead, or `CameraBoundsMode::Frustum` to keep everything the camera can see
+    /// within the bounds.
+    /// Defaults to `CameraBoundsMode::Focus`.
+    pub bounds_mode: CameraBoundsMode,
+    /// The plane the camera moves along, i.e. the direction of 'up'. Heights (including the
+    /// ground the camera follows) are measured along its normal, and `bounds` are on the plane.
+    /// For Z-up worlds, or 2.5D games on the XY plane, use `InfinitePlane3d::new(Vec3::Z)`. The
+    /// rotation of `focus` and `target_focus` is kept upright relative to the plane.
+    /// Defaults to `InfinitePlane3d::new(Vec3::Y)`.
+    pub ground_plane: InfinitePlane3d,
     /// The current angle in radians of the camera, where a value of `0.0` is looking directly down
     /// (-Y), and a value of `TAU / 4.0` (90 degrees) is looking directly forward.
     /// If you want to customise the angle, set `min_angle` instead.
@@ -109,16 +556,45 @@ pub struct RtsCamera {
     /// If you want to customise the angle, this is what you want to change.
     /// Defaults to 25 degrees.
     pub min_angle: f32,
+    /// The maximum angle of the camera, reached when fully zoomed in with `dynamic_angle`. Also
+    /// limits how far the player can tilt the camera (see `RtsCamera::tilt`).
+    /// Defaults to 72 degrees.
+    pub max_angle: f32,
+    /// How far the player has tilted the camera away from the angle set by `dynamic_angle`, in
+    /// radians. Updated by `RtsCamera::tilt` when `dynamic_angle` is enabled.
+    /// Defaults to `0.0`.
+    pub angle_offset: f32,
     /// Whether the camera should increase its angle the more you zoom in, so you can see
     /// characters up close from a sideways view instead of top down.
     /// If this is
     /// Defaults to `true`.
     pub dynamic_angle: bool,
-    /// The amount of smoothing applied to the camera movement. Should be a value between `0.0` and
-    /// `1.0`. Set to `0.0` to disable smoothing. `1.0` is infinite smoothing (the camera won't
-    /// move).
+    /// The amount of smoothing applied to panning (movement of the focus along the ground). Should
+    /// be a value between `0.0` and `1.0`. Set to `0.0` to disable smoothing. `1.0` is infinite
+    /// smoothing (the camera won't move). Use `set_smoothness` to set this and the other
+    /// smoothness fields (except `height_smoothness`) together.
+    /// Defaults to `0.3`.
+    pub pan_smoothness: f32,
+    /// The amount of smoothing applied to zooming. Same range as `pan_smoothness`.
+    /// Defaults to `0.3`.
+    pub zoom_smoothness: f32,
+    /// The amount of smoothing applied to rotation of the focus. Same range as `pan_smoothness`.
     /// Defaults to `0.3`.
-    pub smoothness: f32,
+    pub rotate_smoothness: f32,
+    /// The amount of smoothing applied to changes in the camera's angle. Same range as
+    /// `pan_smoothness`.
+    /// Defaults to `0.3`.
+    pub angle_smoothness: f32,
+    /// The amount of smoothing applied to the height of the camera as it follows the ground,
+    /// separately from `pan_smoothness`. Higher values make the camera glide over cliffs and other
+    /// sudden changes in terrain height instead of jolting up or down. Same range as
+    /// `pan_smoothness`.
+    /// Defaults to `0.3`.
+    pub height_smoothness: f32,
+    /// The maximum speed the camera can move up or down while following the ground, in world
+    /// units per second, or `None` for no limit.
+    /// Defaults to `None`.
+    pub max_vertical_speed: Option<f32>,
     /// The current focus of the camera, including the orientation (which way is forward). The
     /// camera's actual transform is calculated based on this transform.
     /// Updated automatically.
@@ -145,34 +621,359 @@ pub struct RtsCamera {
     /// set the starting zoom.
     /// Defaults to `0.0`.
     pub target_zoom: f32,
-    /// Whether the camera should snap to `target_focus` and `target_zoom`. Will be set to
-    /// `false` after one frame. Useful if you want to lock the camera to a specific target (e.g.
-    /// to follow a unit), by setting `target_focus` and setting this to `true` on every frame.
+    /// How zoom maps to the camera's height between `height_max` (`0.0` zoom) and `height_min`
+    /// (`1.0` zoom). For example, `EaseFunction::QuadraticOut` makes the first half of the zoom
+    /// range cover most of the height, so zooming in close is finer grained. Should be monotonic
+    /// (i.e. not overshoot) for `ZoomMode::WorldUnits` to work correctly.
+    /// Defaults to `EaseFunction::Linear`.
+    pub zoom_curve: EaseFunction,
+    /// How zoom is applied when the camera has an orthographic projection, which looks the same
+    /// size regardless of height. Has no effect on perspective cameras.
+    /// Defaults to `OrthographicZoom::Scale { min: 0.25, max: 1.0 }`.
+    pub orthographic_zoom: OrthographicZoom,
+    /// How zoom is applied when the camera has a perspective projection. Has no effect on
+    /// orthographic cameras.
+    /// Defaults to `PerspectiveZoom::Height`.
+    pub perspective_zoom: PerspectiveZoom,
+    /// Moves the point the camera looks at away from the focus, relative to the focus (so +X is
+    /// right and -Z is forward along the ground), e.g. to keep the focus in the lower part of the
+    /// screen.
+    /// Defaults to `Vec3::ZERO`.
+    pub look_offset: Vec3,
+    /// Moves the camera in its own local space (so +X is right on screen and +Y is up) after it
+    /// has been placed, e.g. for a slight compositional offset. Only this entity's `Transform` is
+    /// written, so children (e.g. effects or a second camera) can be attached to it freely.
+    /// Defaults to `Vec3::ZERO`.
+    pub camera_local_offset: Vec3,
+    /// Whether something else (e.g. a cutscene or a tweening library) is moving the camera. While
+    /// set, the camera's `Transform` and `Projection` aren't written, but the focus, zoom and
+    /// angle are still updated as usual, and `ObstacleAvoidance` is skipped. When it's cleared,
+    /// they're found again from wherever the camera was left in the world, even if it has a
+    /// parent (see `sync_from_transform`), so it carries on from there without jumping back.
     /// Defaults to `false`.
-    pub snap: bool,
+    pub external_transform_control: bool,
+    /// Which parts of the camera should snap immediately to their targets, skipping smoothing.
+    /// Will be reset to `RtsCameraSnap::NONE` after one frame. Useful if you want to lock the
+    /// camera to a specific target (e.g. to follow a unit), by setting `target_focus` and setting
+    /// this to `RtsCameraSnap::TRANSLATION` on every frame.
+    /// Defaults to `RtsCameraSnap::NONE`.
+    pub snap: RtsCameraSnap,
+    /// The minimum distance the camera will keep from any mesh marked with `CameraObstacle`. If an
+    /// obstacle is between the focus and the camera (or closer than this to the camera), the
+    /// camera is moved towards the focus until it's at least this far away from the obstacle.
+    /// Defaults to `0.5`.
+    pub obstacle_clearance: f32,
+    /// What the camera does when a `CameraObstacle` is between it and the focus.
+    /// Defaults to `ObstacleAvoidance::PullIn`.
+    pub obstacle_avoidance: ObstacleAvoidance,
+    /// Changes in ground height under the focus that are smaller than this are ignored, so small
+    /// ledges, curbs, and bumps don't make the camera bob up and down. Larger changes (e.g. hills)
+    /// are still followed. Note that gentle slopes will be followed in steps of this size, which
+    /// is smoothed out by `height_smoothness`.
+    /// Defaults to `0.0` (follow every change in height).
+    pub ground_step_tolerance: f32,
+    /// The minimum distance the camera keeps above any `Ground`, or `None` to only keep
+    /// `height_min` above the focus. On steep slopes, the ground behind the focus can rise above
+    /// the camera when zoomed in. With this set, the ground along the line from the focus to the
+    /// camera is checked, and the camera is moved out along it as far as needed to clear it,
+    /// without changing `target_zoom` (see `ground_clearance_zoom`). Has no effect
+    /// when zoom doesn't change the camera's height (see `orthographic_zoom` and
+    /// `perspective_zoom`).
+    /// Defaults to `None`.
+    pub ground_clearance: Option<f32>,
+    /// How far in front of any `Ground` the camera is kept along the line from the focus to the
+    /// camera, or `None` to not check. If the ground is in the way (e.g. a cliff behind a tilted
+    /// camera), the camera is moved towards the focus until it's at least this far in front of
+    /// it, so it never ends up inside the terrain. Unlike `ground_clearance`, this moves the
+    /// camera's `Transform` directly, without changing the zoom.
+    /// Defaults to `None`.
+    pub near_clip_margin: Option<f32>,
+    /// How the height of the ground below `target_focus` is found. Sampling several points and
+    /// combining them stops the camera jittering over small bumps, trenches, or mesh seams.
+    /// Defaults to `GroundSampling::Single`.
+    pub ground_sampling: GroundSampling,
+    /// How far (in world units) `target_focus` has to move along the XZ plane before the ground
+    /// below it is found again. Until then, the last ground height is reused, which saves ray
+    /// casting every frame while the camera is still. See also `RtsCameraGroundCache`.
+    /// Defaults to `0.0` (find the ground again whenever the camera moves).
+    pub ground_recast_distance: f32,
+    /// How long the last ground height can be reused for while the camera isn't moving, so that
+    /// changes to the terrain are eventually picked up. `None` reuses it until the camera moves
+    /// or `RtsCameraGroundCache::force_ground_refresh` is called.
+    /// Defaults to `Some(Duration::from_millis(250))`.
+    pub ground_refresh_interval: Option<Duration>,
+    /// The height (along the `ground_plane` normal) of an infinite plane that's used as the ground
+    /// when there's no `Ground` below `target_focus`, e.g. when panning off the edge of the map or
+    /// before streamed terrain has loaded. `None` keeps the height of the last ground that was
+    /// hit. `RtsCameraGroundFallback` is sent when a camera starts using it.
+    /// Defaults to `None`.
+    pub fallback_ground: Option<f32>,
+    /// The lowest height (along the `ground_plane` normal) the focus follows the ground down to,
+    /// e.g. sea level, so the camera floats over deep water or chasms instead of plunging to the
+    /// bottom. Also applies to `ground_point`.
+    /// Defaults to `None`.
+    pub ground_height_min: Option<f32>,
+    /// The highest height (along the `ground_plane` normal) the focus follows the ground up to.
+    /// Also applies to `ground_point`.
+    /// Defaults to `None`.
+    pub ground_height_max: Option<f32>,
+    /// Whether `ground_height_min` also acts as a solid plane for ray casts from the cursor (grab
+    /// pan and zooming towards the cursor), so dragging over deep water grabs the surface of the
+    /// water rather than the seafloor.
+    /// Defaults to `false`.
+    pub ground_height_min_blocks_cursor: bool,
+    /// When the camera snaps to its targets after being added. Waiting for the ground avoids the
+    /// camera starting at the wrong height when the level loads asynchronously (e.g. from a glTF
+    /// scene) and spawns its `Ground` after the camera.
+    /// Defaults to `RtsCameraInitMode::Immediate`.
+    pub init_mode: RtsCameraInitMode,
+    /// The view that `reset_orientation` returns the camera to.
+    /// Defaults to `RtsCameraResetView::default()` (facing north without changing zoom or angle).
+    pub reset_view: RtsCameraResetView,
+    /// The scripted move currently in progress, if any. Start one with `fly_to`.
+    /// Defaults to `None`.
+    pub flight: Option<RtsCameraFlight>,
+    /// Whether the camera is returning to `reset_view` after `reset_orientation` was called.
+    /// Updated automatically.
+    /// Defaults to `false`.
+    pub resetting: bool,
+    /// The point on the ground directly below `target_focus`, as found by the most recent ground
+    /// ray cast. Unlike the `target_focus` height, this isn't affected by `ground_step_tolerance`.
+    /// If there is no ground below the camera, this is on `fallback_ground` if set, or otherwise
+    /// isn't updated. See also `ground_focus`.
+    /// Updated automatically.
+    /// Defaults to `Vec3::ZERO`.
+    pub ground_point: Vec3,
+    /// The furthest the camera can currently zoom in while staying `ground_clearance` above the
+    /// ground. The camera's height is limited to this zoom level without changing `zoom` or
+    /// `target_zoom`, so it zooms back in once the ground is out of the way.
+    /// Updated automatically.
+    /// Defaults to `1.0`.
+    pub ground_clearance_zoom: f32,
 }
 
 impl Default for RtsCamera {
     fn default() -> Self {
         RtsCamera {
-            bounds: Aabb2d::new(Vec2::ZERO, Vec2::new(20.0, 20.0)),
+            bounds: CameraBounds::default(),
+            bounds_mode: CameraBoundsMode::Focus,
+            ground_plane: InfinitePlane3d::new(Vec3::Y),
             height_min: 2.0,
             height_max: 30.0,
             angle: 20.0f32.to_radians(),
             target_angle: 20.0f32.to_radians(),
             min_angle: 20.0f32.to_radians(),
+            max_angle: MAX_ANGLE,
+            angle_offset: 0.0,
             dynamic_angle: true,
-            smoothness: 0.3,
+            pan_smoothness: 0.3,
+            zoom_smoothness: 0.3,
+            rotate_smoothness: 0.3,
+            angle_smoothness: 0.3,
+            height_smoothness: 0.3,
+            max_vertical_speed: None,
             focus: Transform::IDENTITY,
             target_focus: Transform::IDENTITY,
             zoom: 0.0,
             target_zoom: 0.0,
-            snap: false,
+            zoom_curve: EaseFunction::Linear,
+            orthographic_zoom: OrthographicZoom::Scale {
+                min: 0.25,
+                max: 1.0,
+            },
+            perspective_zoom: PerspectiveZoom::Height,
+            look_offset: Vec3::ZERO,
+            camera_local_offset: Vec3::ZERO,
+            external_transform_control: false,
+            snap: RtsCameraSnap::NONE,
+            obstacle_clearance: 0.5,
+            obstacle_avoidance: ObstacleAvoidance::PullIn,
+            ground_step_tolerance: 0.0,
+            ground_clearance: None,
+            near_clip_margin: None,
+            fallback_ground: None,
+            ground_height_min: None,
+            ground_height_max: None,
+            ground_height_min_blocks_cursor: false,
+            init_mode: RtsCameraInitMode::Immediate,
+            reset_view: RtsCameraResetView::default(),
+            ground_sampling: GroundSampling::Single,
+            ground_recast_distance: 0.0,
+            ground_refresh_interval: Some(Duration::from_millis(250)),
+            flight: None,
+            resetting: false,
+            ground_point: Vec3::ZERO,
+            ground_clearance_zoom: 1.0,
         }
     }
 }
 
+/// Controls which parts of an `RtsCamera` snap to their targets on the next frame. See
+/// `RtsCamera.snap`.
+/// # Example
+/// ```
+/// # use bevy_rts_camera::RtsCameraSnap;
+/// // Instantly match a unit's rotation, but smoothly move to its position
+/// let snap = RtsCameraSnap {
+///     rotation: true,
+///     ..RtsCameraSnap::NONE
+/// };
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtsCameraSnap {
+    /// Snap the XZ of `focus` to `target_focus`.
+    pub translation: bool,
+    /// Snap the rotation of `focus` to `target_focus`.
+    pub rotation: bool,
+    /// Snap `zoom` to `target_zoom`.
+    pub zoom: bool,
+    /// Snap `angle` to `target_angle`.
+    pub angle: bool,
+}
+
+impl RtsCameraSnap {
+    /// Don't snap anything.
+    pub const NONE: Self = Self {
+        translation: false,
+        rotation: false,
+        zoom: false,
+        angle: false,
+    };
+    /// Only snap the XZ translation.
+    pub const TRANSLATION: Self = Self {
+        translation: true,
+        ..Self::NONE
+    };
+    /// Snap everything.
+    pub const ALL: Self = Self {
+        translation: true,
+        rotation: true,
+        zoom: true,
+        angle: true,
+    };
+}
+
+/// The part of an `RtsCamera`'s state that describes where the player has moved it, for saving
+/// and restoring the camera, e.g. in save games. See `RtsCamera::save_state`.
+/// Serializable with the `serde` feature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtsCameraSaveState {
+    /// See `RtsCamera.target_focus`.
+    pub focus: Transform,
+    /// See `RtsCamera.target_zoom`.
+    pub zoom: f32,
+    /// See `RtsCamera.target_angle`.
+    pub angle: f32,
+    /// See `RtsCamera.angle_offset`.
+    pub angle_offset: f32,
+}
+
 impl RtsCamera {
+    /// Returns the camera's focus, zoom and angle, as they will be once smoothing finishes, to be
+    /// restored later with `restore_state`.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::{RtsCamera, RtsCameraSaveState};
+    /// #[derive(Resource)]
+    /// struct SavedCamera(RtsCameraSaveState);
+    ///
+    /// fn save_camera(cam_q: Query<&RtsCamera>, mut commands: Commands) {
+    ///     if let Ok(cam) = cam_q.get_single() {
+    ///         commands.insert_resource(SavedCamera(cam.save_state()));
+    ///     }
+    /// }
+    ///
+    /// fn load_camera(mut cam_q: Query<&mut RtsCamera>, saved: Res<SavedCamera>) {
+    ///     for mut cam in cam_q.iter_mut() {
+    ///         cam.restore_state(&saved.0);
+    ///     }
+    /// }
+    /// ```
+    pub fn save_state(&self) -> RtsCameraSaveState {
+        RtsCameraSaveState {
+            focus: self.target_focus,
+            zoom: self.target_zoom,
+            angle: self.target_angle,
+            angle_offset: self.angle_offset,
+        }
+    }
+
+    /// Moves the camera to a state returned by `save_state`, immediately rather than smoothly.
+    /// Cancels any `fly_to` move in progress. The height of the focus still follows the ground.
+    pub fn restore_state(&mut self, state: &RtsCameraSaveState) {
+        self.target_focus = state.focus;
+        self.target_zoom = state.zoom.clamp(0.0, 1.0);
+        self.target_angle = state.angle;
+        self.angle_offset = state.angle_offset;
+        self.flight = None;
+        self.reset_smoothing();
+    }
+
+    /// Sets the focus, zoom and angle (and their targets) to those of a camera at `transform`,
+    /// keeping the focus at its current height. The angle is clamped to `min_angle` and
+    /// `max_angle`, and if the camera is higher or lower than its zoom allows, the focus is moved
+    /// up or down instead. Zoom is assumed to change the camera's height.
+    /// Use this when taking over from another camera controller (e.g. a free-fly debug camera),
+    /// so the camera carries on from where it was instead of jumping back to its old focus.
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::RtsCamera;
+    /// let mut cam = RtsCamera::default();
+    /// let debug_camera = Transform::from_xyz(0.0, 20.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y);
+    /// cam.sync_from_transform(&debug_camera);
+    /// assert!(cam.target_focus.translation.abs_diff_eq(Vec3::ZERO, 1e-4));
+    /// assert!((cam.camera_height() - 20.0).abs() < 1e-4);
+    /// ```
+    pub fn sync_from_transform(&mut self, transform: &Transform) {
+        let up = *self.ground_plane.normal;
+        let forward = *transform.forward();
+        let max_angle = self.max_angle.max(self.min_angle);
+        let angle = (-forward.dot(up))
+            .clamp(-1.0, 1.0)
+            .acos()
+            .clamp(self.min_angle, max_angle);
+        // Looking straight down, the camera's up is the direction it's facing along the ground
+        let flat_forward = forward
+            .reject_from_normalized(up)
+            .try_normalize()
+            .or_else(|| transform.up().reject_from_normalized(up).try_normalize())
+            .unwrap_or_else(|| self.plane_to_world(Vec3::NEG_Z));
+        let mut focus = Transform::IDENTITY.looking_to(flat_forward, up);
+
+        // Work back from the camera to the point it's looking at, at the height of the focus
+        let position = transform.translation - transform.rotation * self.camera_local_offset;
+        let look_height = self.world_to_plane(self.focus.translation).y
+            + self.world_to_plane(focus.rotation * self.look_offset).y;
+        let zoom = self.zoom_at_height(self.world_to_plane(position).y - look_height);
+        let height = self.height_at_zoom(zoom);
+        focus.translation = position
+            - up * height
+            - focus.back() * height * angle.tan()
+            - focus.rotation * self.look_offset;
+
+        self.target_focus = focus;
+        self.target_zoom = zoom;
+        self.target_angle = angle;
+        if self.dynamic_angle {
+            self.angle_offset = angle - self.min_angle.lerp(max_angle, ease_in_circular(zoom));
+        }
+        self.flight = None;
+        self.reset_smoothing();
+    }
+
+    /// Sets `pan_smoothness`, `zoom_smoothness`, `rotate_smoothness` and `angle_smoothness` all to
+    /// `smoothness`.
+    pub fn set_smoothness(&mut self, smoothness: f32) {
+        self.pan_smoothness = smoothness;
+        self.zoom_smoothness = smoothness;
+        self.rotate_smoothness = smoothness;
+        self.angle_smoothness = smoothness;
+    }
+
     /// Sets the camera's position, angle and focus immediately to their current smoothing destination.
     pub fn reset_smoothing(&mut self) {
         self.focus.translation = self.target_focus.translation;
@@ -180,129 +981,2048 @@ impl RtsCamera {
         self.zoom = self.target_zoom;
         self.angle = self.target_angle;
     }
+
+    /// Returns the point on the ground the camera is currently centered on. `focus` is smoothed
+    /// (including its height), so it may be above or below the ground while the camera is moving,
+    /// whereas this is always at the height of `ground_point`.
+    pub fn ground_focus(&self) -> Vec3 {
+        self.with_height_of(self.focus.translation, self.ground_point)
+    }
+
+    /// Returns the point the camera is looking at, which is `ground_focus` moved by `look_offset`
+    /// (relative to the focus). `camera_local_offset` isn't included, as it moves the camera
+    /// without turning it towards this point.
+    pub fn look_target(&self) -> Vec3 {
+        self.ground_focus() + self.focus.rotation * self.look_offset
+    }
+
+    /// Returns how high the camera currently is above `focus`, along the normal of
+    /// `ground_plane`. Doesn't include the camera being pulled in by `obstacle_avoidance`, and
+    /// orthographic cameras using `OrthographicZoom::Scale` (or perspective cameras using
+    /// `PerspectiveZoom::Fov`) stay at `height_max` instead.
+    pub fn camera_height(&self) -> f32 {
+        self.height_at_zoom(self.zoom)
+    }
+
+    /// Returns where the camera currently is relative to `focus`, with the same caveats as
+    /// `camera_height`. Adding this to `focus.translation` gives the camera's translation.
+    pub fn camera_offset(&self) -> Vec3 {
+        self.offset_at(&self.focus, self.zoom, self.angle)
+    }
+
+    /// Returns the direction the camera is facing, flattened onto `ground_plane`, e.g. for
+    /// placing things in front of the camera or orienting an audio listener.
+    pub fn forward_flat(&self) -> Dir3 {
+        Dir3::new(
+            self.focus
+                .forward()
+                .reject_from_normalized(*self.ground_plane.normal),
+        )
+        .unwrap_or_else(|_| Dir3::new_unchecked(self.plane_to_world(Vec3::NEG_Z)))
+    }
+
+    /// Tilts the camera by `delta` radians, where positive values tilt it up towards the horizon,
+    /// keeping it between `min_angle` and `max_angle`. With `dynamic_angle`, this changes
+    /// `angle_offset` so the angle still follows the zoom, otherwise it changes `target_angle`
+    /// directly.
+    pub fn tilt(&mut self, delta: f32) {
+        let max_angle = self.max_angle.max(self.min_angle);
+        if self.dynamic_angle {
+            // Don't let the offset build up past what can actually be applied at this zoom
+            let range = max_angle - self.min_angle;
+            let dynamic_angle = self
+                .min_angle
+                .lerp(max_angle, ease_in_circular(self.target_zoom));
+            let offset = (self.angle_offset + delta).clamp(-range, range);
+            let offset = (dynamic_angle + offset).clamp(self.min_angle, max_angle) - dynamic_angle;
+            if self.angle_offset != offset {
+                self.angle_offset = offset;
+            }
+        } else {
+            let target_angle = (self.target_angle + delta).clamp(self.min_angle, max_angle);
+            if self.target_angle != target_angle {
+                self.target_angle = target_angle;
+            }
+        }
+    }
+
+    /// Moves the camera to `target` (position and rotation) and `zoom` over exactly `duration`,
+    /// following the `easing` curve, instead of the usual exponential smoothing. A
+    /// `CameraMoveComplete` event is sent when the camera arrives. The vertical position still
+    /// follows the ground. Replaces any move already in progress.
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::RtsCamera;
+    /// fn go_to_event(mut cam_q: Query<&mut RtsCamera>) {
+    ///     for mut cam in cam_q.iter_mut() {
+    ///         cam.fly_to(
+    ///             Transform::from_xyz(10.0, 0.0, -5.0),
+    ///             0.5,
+    ///             Duration::from_secs(2),
+    ///             EaseFunction::CubicInOut,
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn fly_to(
+        &mut self,
+        target: Transform,
+        zoom: f32,
+        duration: Duration,
+        easing: EaseFunction,
+    ) {
+        self.flight = Some(RtsCameraFlight {
+            from: self.focus,
+            from_zoom: self.zoom,
+            to: target,
+            to_zoom: zoom.clamp(0.0, 1.0),
+            duration,
+            elapsed: Duration::ZERO,
+            easing,
+        });
+        self.target_focus.translation =
+            self.with_height_of(self.target_focus.translation, target.translation);
+    }
+
+    /// Smoothly turns the camera back to face `reset_view.yaw`, and changes the zoom and angle to
+    /// those in `reset_view` if set, e.g. when the player clicks on a compass. A
+    /// `RtsCameraResetComplete` event is sent when the camera gets there, unless it's turned or
+    /// zoomed somewhere else first.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::RtsCamera;
+    /// fn compass_clicked(mut cam_q: Query<&mut RtsCamera>) {
+    ///     for mut cam in cam_q.iter_mut() {
+    ///         cam.reset_orientation();
+    ///     }
+    /// }
+    /// ```
+    pub fn reset_orientation(&mut self) {
+        self.set_target_yaw(self.reset_view.yaw);
+        if let Some(zoom) = self.reset_view.zoom {
+            self.target_zoom = zoom.clamp(0.0, 1.0);
+        }
+        if let Some(angle) = self.reset_view.angle {
+            self.target_angle = angle.clamp(self.min_angle, self.max_angle);
+        }
+        self.resetting = true;
+    }
+
+    /// Centers the camera on `points` and zooms in as far as possible while keeping them all on
+    /// screen, e.g. to jump to a battle or show the whole map at the end of a mission. `padding` is
+    /// the fraction of the view kept clear on each side, e.g. `0.1` keeps the points out of the
+    /// outer 10% of the screen. The camera keeps its current rotation and angle (or the angle
+    /// `dynamic_angle` gives at the new zoom), so rotate it first if needed. Zooms fully out if the
+    /// points don't fit even then. Use `framing` instead to move there with `fly_to`.
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::RtsCamera;
+    /// # #[derive(Component)]
+    /// # struct Unit;
+    /// fn show_army(
+    ///     mut cam_q: Query<(&mut RtsCamera, &Projection)>,
+    ///     unit_q: Query<&Transform, With<Unit>>,
+    /// ) {
+    ///     let points = unit_q.iter().map(|tfm| tfm.translation).collect::<Vec<_>>();
+    ///     for (mut cam, projection) in cam_q.iter_mut() {
+    ///         cam.frame_points(&points, 0.1, projection);
+    ///     }
+    /// }
+    /// ```
+    pub fn frame_points(&mut self, points: &[Vec3], padding: f32, projection: &Projection) {
+        let Some((focus, zoom)) = self.framing(points, padding, projection) else {
+            return;
+        };
+        self.target_focus.translation = focus.translation;
+        self.target_zoom = zoom;
+        self.flight = None;
+    }
+
+    /// Returns the focus and zoom that `frame_points` would move the camera to, or `None` if
+    /// `points` is empty (or the orthographic `projection` hasn't been sized yet).
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_rts_camera::RtsCamera;
+    /// let cam = RtsCamera::default();
+    /// let projection = Projection::Perspective(PerspectiveProjection::default());
+    /// let near = [Vec3::new(-2.0, 0.0, -2.0), Vec3::new(2.0, 0.0, 2.0)];
+    /// let far = [Vec3::new(-6.0, 0.0, -6.0), Vec3::new(6.0, 0.0, 6.0)];
+    /// let (focus, near_zoom) = cam.framing(&near, 0.1, &projection).unwrap();
+    /// let (_, far_zoom) = cam.framing(&far, 0.1, &projection).unwrap();
+    /// assert_eq!(focus.translation, Vec3::ZERO);
+    /// // Points further apart need the camera to be zoomed out further
+    /// assert!(far_zoom < near_zoom);
+    /// ```
+    pub fn framing(
+        &self,
+        points: &[Vec3],
+        padding: f32,
+        projection: &Projection,
+    ) -> Option<(Transform, f32)> {
+        let (min, max) = points
+            .iter()
+            .map(|point| self.world_to_plane(*point))
+            .fold(None, |bounds: Option<(Vec3, Vec3)>, point| match bounds {
+                Some((min, max)) => Some((min.min(point), max.max(point))),
+                None => Some((point, point)),
+            })?;
+        if let Projection::Orthographic(ortho) = projection {
+            if ortho.area.is_empty() || ortho.scale <= 0.0 {
+                return None;
+            }
+        }
+        let focus = self
+            .target_focus
+            .with_translation(self.plane_to_world((min + max) / 2.0));
+        // How far from the center of the screen the points can be, where `1.0` is the edge
+        let limit = 1.0 - 2.0 * padding.clamp(0.0, 0.49);
+        let fits = |zoom: f32| {
+            let tfm = self.camera_transform(
+                &focus,
+                self.height_zoom(zoom, Some(projection)),
+                self.angle_at_zoom(zoom),
+            );
+            let view_from_world = tfm.compute_matrix().inverse();
+            points.iter().all(|point| {
+                let point = view_from_world.transform_point3(*point);
+                match projection {
+                    Projection::Perspective(persp) => {
+                        let depth = -point.z;
+                        let fov = self.fov_at_zoom(zoom, projection).unwrap_or(persp.fov);
+                        let half_height = depth * (fov / 2.0).tan() * limit;
+                        let half_width = half_height * persp.aspect_ratio;
+                        depth > persp.near
+                            && point.x.abs() <= half_width
+                            && point.y.abs() <= half_height
+                    }
+                    Projection::Orthographic(ortho) => {
+                        let scale = self
+                            .ortho_scale_at_zoom(zoom, projection)
+                            .unwrap_or(ortho.scale);
+                        let area = Rect::from_corners(
+                            ortho.area.min * scale / ortho.scale,
+                            ortho.area.max * scale / ortho.scale,
+                        );
+                        let offset = (point.xy() - area.center()).abs();
+                        offset.x <= area.half_size().x * limit
+                            && offset.y <= area.half_size().y * limit
+                    }
+                }
+            })
+        };
+        if fits(1.0) {
+            return Some((focus, 1.0));
+        }
+        if !fits(0.0) {
+            return Some((focus, 0.0));
+        }
+        // Zooming in only shrinks the view, so search for the closest zoom that still fits
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..ZOOM_SEARCH_ITERATIONS {
+            let mid = (low + high) / 2.0;
+            if fits(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some((focus, low))
+    }
+}
+
+/// How an `RtsCamera` finds the height of the ground below its focus. See
+/// `RtsCamera.ground_sampling`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GroundSampling {
+    /// Use the height of the ground directly below the focus.
+    Single,
+    /// Use the average height of the ground below the focus and four points around it, `spread`
+    /// times the camera's height away (so the pattern grows as the camera zooms out).
+    Average {
+        /// How far the outer points are from the focus, relative to the camera's height.
+        spread: f32,
+    },
+    /// Like `Average`, but use the median height instead, which ignores a single point that is
+    /// much higher or lower than the rest (e.g. a rock or a trench).
+    Median {
+        /// How far the outer points are from the focus, relative to the camera's height.
+        spread: f32,
+    },
+}
+
+/// The view that `RtsCamera::reset_orientation` returns an `RtsCamera` to. See
+/// `RtsCamera.reset_view`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtsCameraResetView {
+    /// Which way counts as north, as the yaw (in radians) around the `RtsCamera.ground_plane`
+    /// normal, where `0.0` faces -Z and positive values turn counterclockwise when looking down.
+    /// Defaults to `0.0`.
+    pub yaw: f32,
+    /// The zoom to return to, or `None` to keep the current zoom.
+    /// Defaults to `None`.
+    pub zoom: Option<f32>,
+    /// The angle to return to, or `None` to keep the current angle. Has no effect with
+    /// `RtsCamera.dynamic_angle`.
+    /// Defaults to `None`.
+    pub angle: Option<f32>,
+}
+
+impl Default for RtsCameraResetView {
+    fn default() -> Self {
+        RtsCameraResetView {
+            yaw: 0.0,
+            zoom: None,
+            angle: None,
+        }
+    }
+}
+
+/// When an `RtsCamera` snaps to its targets after being added. See `RtsCamera.init_mode`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RtsCameraInitMode {
+    /// Snap straight away, before the height of the ground is known.
+    Immediate,
+    /// Wait until `Ground` is found below the focus, and then snap to it. If no ground is found
+    /// within `timeout`, snap anyway.
+    WaitForGround {
+        /// How long to wait for ground before giving up.
+        timeout: Duration,
+    },
+}
+
+/// How zoom is applied to an `RtsCamera` with an orthographic projection. See
+/// `RtsCamera.orthographic_zoom`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrthographicZoom {
+    /// Zoom moves the camera between `height_max` and `height_min`, the same as a perspective
+    /// camera. As the view doesn't get smaller as the camera gets closer, this only changes the
+    /// angle (see `RtsCamera.dynamic_angle`).
+    Height,
+    /// Zoom changes `OrthographicProjection::scale` between `max` (at `0.0` zoom) and `min` (at
+    /// `1.0` zoom), and the camera stays at `height_max`.
+    Scale {
+        /// The scale when fully zoomed in.
+        min: f32,
+        /// The scale when fully zoomed out.
+        max: f32,
+    },
+    /// Zoom changes both the camera's height (like `Height`) and its scale (like `Scale`).
+    HeightAndScale {
+        /// The scale when fully zoomed in.
+        min: f32,
+        /// The scale when fully zoomed out.
+        max: f32,
+    },
+}
+
+/// How zoom is applied to an `RtsCamera` with a perspective projection. See
+/// `RtsCamera.perspective_zoom`.
+/// Changing the field of view means `ZoomDriven::fov` shouldn't be used on the same camera.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PerspectiveZoom {
+    /// Zoom moves the camera between `height_max` and `height_min`.
+    Height,
+    /// Zoom changes `PerspectiveProjection::fov` (in radians) between `max` (at `0.0` zoom) and
+    /// `min` (at `1.0` zoom), and the camera stays at `height_max`. Useful when the camera's
+    /// height needs to stay the same, e.g. for fog of war or occlusion.
+    Fov {
+        /// The field of view when fully zoomed in.
+        min: f32,
+        /// The field of view when fully zoomed out.
+        max: f32,
+    },
+    /// Zoom changes both the camera's height (like `Height`) and its field of view (like `Fov`),
+    /// e.g. narrowing the field of view while moving in for a dolly zoom style effect.
+    HeightAndFov {
+        /// The field of view when fully zoomed in.
+        min: f32,
+        /// The field of view when fully zoomed out.
+        max: f32,
+    },
+}
+
+impl RtsCamera {
+    /// Returns the rotation from the default Y-up space to that of `ground_plane`, where Y is the
+    /// plane's normal.
+    pub(crate) fn plane_rotation(&self) -> Quat {
+        Quat::from_rotation_arc(Vec3::Y, *self.ground_plane.normal)
+    }
+
+    /// Returns the compass heading of `target_focus` in degrees, from `0.0` (inclusive) to `360.0`
+    /// (exclusive), where `0.0` is north (-Z) and `90.0` is east (+X). Useful for compass widgets
+    /// and rotating minimaps. See also `CameraHeadingChanged`.
+    /// # Example
+    /// ```
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// # use bevy_rts_camera::RtsCamera;
+    /// let mut cam = RtsCamera::default();
+    /// assert_eq!(cam.yaw_degrees(), 0.0);
+    /// // Turn right, to face east
+    /// cam.target_focus.rotate_y(-FRAC_PI_2);
+    /// assert!((cam.yaw_degrees() - 90.0).abs() < 1e-3);
+    /// ```
+    pub fn yaw_degrees(&self) -> f32 {
+        heading_degrees(self.target_yaw())
+    }
+
+    /// Returns the yaw of `target_focus` around the `ground_plane` normal, where `0.0` faces north
+    /// (-Z) and negative values turn clockwise when looking down.
+    pub(crate) fn target_yaw(&self) -> f32 {
+        self.yaw_of(self.target_focus.rotation)
+    }
+
+    /// Returns the yaw of `rotation` around the `ground_plane` normal (see `target_yaw`).
+    fn yaw_of(&self, rotation: Quat) -> f32 {
+        let forward = self.world_to_plane(rotation * Vec3::NEG_Z);
+        (-forward.x).atan2(-forward.z)
+    }
+
+    /// Turns `target_focus` to face `yaw` (see `target_yaw`), keeping it upright.
+    pub(crate) fn set_target_yaw(&mut self, yaw: f32) {
+        self.target_focus.rotation = self.plane_rotation() * Quat::from_rotation_y(yaw);
+    }
+
+    /// Converts a world space point (or vector) to the space of `ground_plane`, where Y is the
+    /// height above the plane.
+    pub(crate) fn world_to_plane(&self, point: Vec3) -> Vec3 {
+        self.plane_rotation().inverse() * point
+    }
+
+    /// Converts a point (or vector) in the space of `ground_plane` back to world space.
+    pub(crate) fn plane_to_world(&self, point: Vec3) -> Vec3 {
+        self.plane_rotation() * point
+    }
+
+    /// Converts a world space point (or vector) to the 2D coordinates used by `CameraBounds`.
+    pub(crate) fn to_bounds(&self, point: Vec3) -> Vec2 {
+        let point = self.world_to_plane(point);
+        Vec2::new(point.x, -point.z)
+    }
+
+    /// Returns `point` moved along the normal of `ground_plane` to the same height as `height_of`.
+    pub(crate) fn with_height_of(&self, point: Vec3, height_of: Vec3) -> Vec3 {
+        let mut point = self.world_to_plane(point);
+        point.y = self.world_to_plane(height_of).y;
+        self.plane_to_world(point)
+    }
+
+    /// Returns `point` with its height clamped to `ground_height_min` and `ground_height_max`.
+    pub(crate) fn clamp_ground_height(&self, point: Vec3) -> Vec3 {
+        let mut plane_point = self.world_to_plane(point);
+        let height = plane_point.y;
+        let height = self.ground_height_min.map_or(height, |min| height.max(min));
+        let height = self.ground_height_max.map_or(height, |max| height.min(max));
+        if height == plane_point.y {
+            return point;
+        }
+        plane_point.y = height;
+        self.plane_to_world(plane_point)
+    }
+
+    /// Returns where `ray` from the cursor hits the ground, given where it hit `Ground` (if
+    /// anywhere), stopping it at `ground_height_min` if `ground_height_min_blocks_cursor` is set.
+    #[cfg(feature = "controller")]
+    pub(crate) fn cursor_ground_hit(&self, ray: Ray3d, hit: Option<Vec3>) -> Option<Vec3> {
+        let Some(min) = self
+            .ground_height_min
+            .filter(|_| self.ground_height_min_blocks_cursor)
+        else {
+            return hit;
+        };
+        let above_min = |point: Vec3| self.world_to_plane(point).y >= min;
+        if hit.is_some_and(above_min) {
+            return hit;
+        }
+        let plane_origin = self.plane_to_world(Vec3::Y * min);
+        ray.intersect_plane(plane_origin, self.ground_plane)
+            .map(|distance| ray.get_point(distance))
+            .or(hit)
+    }
+
+    /// Returns `rotation` turned so that its up is the normal of `ground_plane`, keeping its
+    /// facing as close as possible, or `None` if it already is.
+    pub(crate) fn upright_rotation(&self, rotation: Quat) -> Option<Quat> {
+        let up = self.ground_plane.normal;
+        if (rotation * Vec3::Y).abs_diff_eq(*up, UPRIGHT_TOLERANCE) {
+            return None;
+        }
+        let forward = (rotation * Vec3::NEG_Z)
+            .reject_from_normalized(*up)
+            .try_normalize()
+            .unwrap_or_else(|| self.plane_to_world(Vec3::NEG_Z));
+        Some(Transform::IDENTITY.looking_to(forward, up).rotation)
+    }
+
+    /// Returns the angle the camera will have at the given zoom level, which only depends on the
+    /// zoom with `dynamic_angle`.
+    pub(crate) fn angle_at_zoom(&self, zoom: f32) -> f32 {
+        if !self.dynamic_angle {
+            return self.target_angle;
+        }
+        (self
+            .min_angle
+            .lerp(self.max_angle.max(self.min_angle), ease_in_circular(zoom))
+            + self.angle_offset)
+            .clamp(self.min_angle, self.max_angle.max(self.min_angle))
+    }
+
+    /// Returns the `OrthographicProjection::scale` at the given zoom level, or `None` if zoom
+    /// doesn't affect the scale of `projection`.
+    pub(crate) fn ortho_scale_at_zoom(&self, zoom: f32, projection: &Projection) -> Option<f32> {
+        let Projection::Orthographic(_) = projection else {
+            return None;
+        };
+        match self.orthographic_zoom {
+            OrthographicZoom::Height => None,
+            OrthographicZoom::Scale { min, max }
+            | OrthographicZoom::HeightAndScale { min, max } => {
+                let t = EasingCurve::new(0.0, 1.0, self.zoom_curve).sample_clamped(zoom);
+                Some(max.lerp(min, t))
+            }
+        }
+    }
+
+    /// Returns the `PerspectiveProjection::fov` at the given zoom level, or `None` if zoom
+    /// doesn't affect the field of view of `projection`.
+    pub(crate) fn fov_at_zoom(&self, zoom: f32, projection: &Projection) -> Option<f32> {
+        let Projection::Perspective(_) = projection else {
+            return None;
+        };
+        match self.perspective_zoom {
+            PerspectiveZoom::Height => None,
+            PerspectiveZoom::Fov { min, max } | PerspectiveZoom::HeightAndFov { min, max } => {
+                let t = EasingCurve::new(0.0, 1.0, self.zoom_curve).sample_clamped(zoom);
+                Some(max.lerp(min, t))
+            }
+        }
+    }
+
+    /// Returns the zoom level that determines the camera's height, which stays at `0.0`
+    /// (`height_max`) if zoom only changes the scale of an orthographic `projection`, or the
+    /// field of view of a perspective one. Otherwise it's `zoom`, limited to
+    /// `ground_clearance_zoom`.
+    pub(crate) fn height_zoom(&self, zoom: f32, projection: Option<&Projection>) -> f32 {
+        if self.zoom_changes_height(projection) {
+            zoom.min(self.ground_clearance_zoom)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns whether zooming changes the camera's height with the given `projection`, rather
+    /// than only the scale or field of view.
+    fn zoom_changes_height(&self, projection: Option<&Projection>) -> bool {
+        !matches!(
+            (projection, self.orthographic_zoom, self.perspective_zoom),
+            (
+                Some(Projection::Orthographic(_)),
+                OrthographicZoom::Scale { .. },
+                _
+            ) | (
+                Some(Projection::Perspective(_)),
+                _,
+                PerspectiveZoom::Fov { .. }
+            )
+        )
+    }
+
+    /// Returns the height of the camera above the focus at the given zoom level, taking
+    /// `zoom_curve` into account.
+    pub fn height_at_zoom(&self, zoom: f32) -> f32 {
+        let t = EasingCurve::new(0.0, 1.0, self.zoom_curve).sample_clamped(zoom.clamp(0.0, 1.0));
+        self.height_max.lerp(self.height_min, t)
+    }
+
+    /// Returns the zoom level at which the camera is `height` above the focus, i.e. the inverse
+    /// of `height_at_zoom`. Assumes `zoom_curve` is monotonic.
+    pub fn zoom_at_height(&self, height: f32) -> f32 {
+        if self.height_max == self.height_min {
+            return 0.0;
+        }
+        let t = ((height - self.height_max) / (self.height_min - self.height_max)).clamp(0.0, 1.0);
+        if self.zoom_curve == EaseFunction::Linear {
+            return t;
+        }
+        // Arbitrary easing curves can't be inverted directly, so search for it instead
+        let curve = EasingCurve::new(0.0, 1.0, self.zoom_curve);
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..ZOOM_SEARCH_ITERATIONS {
+            let mid = (low + high) / 2.0;
+            if curve.sample_clamped(mid) < t {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        (low + high) / 2.0
+    }
+
+    /// Returns the transform of a camera looking at `focus` with the given `zoom` and `angle`.
+    pub(crate) fn camera_transform(&self, focus: &Transform, zoom: f32, angle: f32) -> Transform {
+        let rotation = focus.rotation * Quat::from_rotation_x(angle - 90f32.to_radians());
+        Transform {
+            translation: focus.translation
+                + focus.rotation * self.look_offset
+                + self.offset_at(focus, zoom, angle)
+                + rotation * self.camera_local_offset,
+            rotation,
+            scale: Vec3::ONE,
+        }
+    }
+
+    /// Returns where a camera looking at `focus` with the given `zoom` and `angle` is, relative to
+    /// `focus`.
+    fn offset_at(&self, focus: &Transform, zoom: f32, angle: f32) -> Vec3 {
+        let camera_height = self.height_at_zoom(zoom);
+        let camera_offset = camera_height * angle.tan();
+        (self.ground_plane.normal * camera_height) + (focus.back() * camera_offset)
+    }
+}
+
+/// A scripted camera move started by `RtsCamera::fly_to`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtsCameraFlight {
+    /// Where the focus was when the move started.
+    pub from: Transform,
+    /// The zoom when the move started.
+    pub from_zoom: f32,
+    /// Where the focus is moving to.
+    pub to: Transform,
+    /// The zoom the camera is moving to.
+    pub to_zoom: f32,
+    /// How long the move takes.
+    pub duration: Duration,
+    /// How long the move has been in progress.
+    pub elapsed: Duration,
+    /// The easing applied to the move.
+    pub easing: EaseFunction,
+}
+
+/// Event sent when a move started with `RtsCamera::fly_to` finishes.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CameraMoveComplete {
+    /// The camera that finished moving.
+    pub camera: Entity,
+}
+
+/// Event sent when a camera finishes returning to its `RtsCamera.reset_view` after
+/// `RtsCamera::reset_orientation`.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraResetComplete {
+    /// The camera that finished resetting.
+    pub camera: Entity,
+}
+
+/// Event that moves `RtsCamera`s to a position, e.g. when the player clicks on the minimap. The
+/// move is applied at the start of `RtsCameraSystemSet`, so the camera still follows the ground
+/// and stays within its bounds. Cancels any `RtsCamera::fly_to` move in progress, and stops
+/// following an entity (see `RtsCameraFollow`) unless it has `break_on_pan` disabled.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::RtsCameraJumpTo;
+/// fn minimap_clicked(mut jump_to: EventWriter<RtsCameraJumpTo>) {
+///     let clicked = Vec3::new(10.0, 0.0, -5.0);
+///     jump_to.send(RtsCameraJumpTo {
+///         instant: true,
+///         ..RtsCameraJumpTo::new(clicked)
+///     });
+/// }
+/// ```
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraJumpTo {
+    /// The camera to move, or `None` for all cameras.
+    pub camera: Option<Entity>,
+    /// Where to move the focus to. The height is replaced by the height of the ground.
+    pub position: Vec3,
+    /// The zoom to change to, if any.
+    pub zoom: Option<f32>,
+    /// Whether to move there immediately instead of smoothly.
+    pub instant: bool,
+}
+
+impl RtsCameraJumpTo {
+    /// Creates an event that smoothly moves all cameras to `position`, without changing the zoom.
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            camera: None,
+            position,
+            zoom: None,
+            instant: false,
+        }
+    }
+}
+
+/// Optional component that, when added to an `RtsCamera`, is updated every frame with the area of
+/// the ground the camera can see: the points where the corners of its view meet the ground plane
+/// at the height of the focus. Useful for drawing the camera's view on a minimap. Parts of the
+/// view above the horizon are limited to the projection's far plane.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, RtsCameraFrustumFootprint};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((RtsCamera::default(), RtsCameraFrustumFootprint::default()));
+/// }
+///
+/// fn update_minimap(cam_q: Query<&RtsCameraFrustumFootprint>) {
+///     for footprint in cam_q.iter() {
+///         // Top down, so only X and Z matter
+///         let outline = footprint.corners.map(|corner| corner.xz());
+///         info!("Draw the view on the minimap: {outline:?}");
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq)]
+pub struct RtsCameraFrustumFootprint {
+    /// The corners of the visible area in world space, in the order bottom left, bottom right,
+    /// top right, top left (as seen on screen).
+    /// Updated automatically.
+    pub corners: [Vec3; 4],
+}
+
+/// Event sent on each frame that the focus of an `RtsCamera` moves (including smoothing and
+/// following the ground).
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraMoved {
+    /// The camera that moved.
+    pub camera: Entity,
+    /// The new `RtsCamera.focus` translation.
+    pub translation: Vec3,
+}
+
+/// Event sent on each frame that the zoom of an `RtsCamera` changes.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraZoomChanged {
+    /// The camera that zoomed.
+    pub camera: Entity,
+    /// The new `RtsCamera.zoom`.
+    pub zoom: f32,
+}
+
+/// Event sent on each frame that the compass heading of an `RtsCamera` changes, as it turns
+/// (including smoothing). Unlike `RtsCameraRotated`, this gives the heading directly.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CameraHeadingChanged {
+    /// The camera that turned.
+    pub camera: Entity,
+    /// The heading of `RtsCamera.focus` in degrees, in the same form as `RtsCamera::yaw_degrees`.
+    pub heading: f32,
+}
+
+/// Event sent on each frame that an `RtsCamera` rotates around the Y axis.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraRotated {
+    /// The camera that rotated.
+    pub camera: Entity,
+    /// The new `RtsCamera.focus` rotation.
+    pub rotation: Quat,
+}
+
+/// Event sent on each frame that `RtsCamera.target_focus` is pushed back inside
+/// `RtsCamera.bounds`, e.g. while the player keeps panning against the edge of the map.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraHitBounds {
+    /// The camera that hit its bounds.
+    pub camera: Entity,
+}
+
+/// Event sent when there's no `Ground` below an `RtsCamera` and it starts following
+/// `RtsCamera.fallback_ground` instead. Not sent again until ground has been hit in between.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraGroundFallback {
+    /// The camera that fell back to the plane.
+    pub camera: Entity,
+    /// Where the focus meets the plane.
+    pub point: Vec3,
 }
 
 /// Marks an entity that should be treated as 'ground'. The RTS camera will stay a certain distance
 /// (based on min/max height and zoom) above any meshes marked with this component (using a ray
 /// cast).
 /// You'll likely want to mark all terrain entities, but not things like buildings, trees, or units.
+/// Everything below a `Ground` entity in the hierarchy is ground too, so it can be added to the
+/// root of a terrain scene rather than each of its meshes. Use `NotGround` to leave parts of it
+/// out, and `GroundLayers` to make cameras follow different ground.
+/// For very large terrain meshes, consider `HeightmapGround` instead.
 #[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
 #[reflect(Component)]
 pub struct Ground;
 
-fn initialize(mut cam_q: Query<&mut RtsCamera, Added<RtsCamera>>) {
-    for mut cam in cam_q.iter_mut() {
+/// Marks an entity below a `Ground` entity in the hierarchy that shouldn't be treated as ground,
+/// along with everything below it (unless it's marked with `Ground` again). Useful for things
+/// like decals or water surfaces that are part of a terrain scene. Also overrides `Ground` on the
+/// same entity.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct NotGround;
+
+/// Marks an entity that the camera should not clip through, such as tall statues or gates. Unlike
+/// `Ground`, obstacles don't affect the height of the focus. Instead, when an obstacle is between
+/// the focus and the camera, the camera is pulled in towards the focus so that it stays
+/// `RtsCamera.obstacle_clearance` away from the obstacle, or the obstacle is marked with
+/// `OccludingCamera`, depending on `RtsCamera.obstacle_avoidance`.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct CameraObstacle;
+
+/// Added to `CameraObstacle`s while they block the view between an `RtsCamera` (using
+/// `ObstacleAvoidance::MarkOccluders`) and its focus, and removed once they don't. Use this to
+/// fade out or hide the obstacle. Hidden obstacles are still checked, so hiding them won't cause
+/// them to flicker.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::OccludingCamera;
+/// fn hide_occluders(
+///     mut added: Query<&mut Visibility, Added<OccludingCamera>>,
+///     mut removed: RemovedComponents<OccludingCamera>,
+///     mut visibility_q: Query<&mut Visibility>,
+/// ) {
+///     for mut visibility in added.iter_mut() {
+///         *visibility = Visibility::Hidden;
+///     }
+///     for entity in removed.read() {
+///         if let Ok(mut visibility) = visibility_q.get_mut(entity) {
+///             *visibility = Visibility::Inherited;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct OccludingCamera;
+
+/// Event sent every frame that `CameraObstacle`s block the view between an `RtsCamera` (using
+/// `ObstacleAvoidance::MarkOccluders`) and its focus, listing them all. An alternative to
+/// `OccludingCamera` when each camera's occluders are needed, e.g. to fade them by how far they
+/// are from the camera.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::RtsCameraOccluded;
+/// fn fade_occluders(mut occluded: EventReader<RtsCameraOccluded>) {
+///     for event in occluded.read() {
+///         for (i, entity) in event.occluders.iter().enumerate() {
+///             info!("{entity} is blocking the view of {} (#{i} from the camera)", event.camera);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct RtsCameraOccluded {
+    /// The camera whose view is blocked.
+    pub camera: Entity,
+    /// The obstacles in the way, nearest to the camera first.
+    pub occluders: Vec<Entity>,
+}
+
+/// What an `RtsCamera` does when a `CameraObstacle` is between it and its focus. See
+/// `RtsCamera.obstacle_avoidance`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObstacleAvoidance {
+    /// Pull the camera in towards the focus so it's in front of the obstacle.
+    #[default]
+    PullIn,
+    /// Leave the camera where it is, and mark every obstacle in the way with `OccludingCamera`
+    /// so they can be faded out.
+    MarkOccluders,
+    /// Ignore obstacles.
+    Ignore,
+}
+
+/// Makes the `RtsCamera` on the same entity follow another entity, by moving `target_focus` to
+/// that entity's position every frame. Removed automatically if the target entity no longer exists.
+/// Use `RtsCameraFollow::behind` to also turn the camera to look the same way as the entity, e.g.
+/// for hero units or replays, or `RtsCameraFollow::soft` to only move the camera when the entity
+/// gets close to the edge of the screen. Remove the component to return to free movement.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, RtsCameraFollow};
+/// fn follow_unit(mut commands: Commands, cam_q: Query<Entity, With<RtsCamera>>, unit: Entity) {
+///     for cam in cam_q.iter() {
+///         commands.entity(cam).insert(RtsCameraFollow::new(unit));
+///     }
+/// }
+///
+/// fn follow_hero(mut commands: Commands, cam_q: Query<Entity, With<RtsCamera>>, hero: Entity) {
+///     for cam in cam_q.iter() {
+///         commands.entity(cam).insert(RtsCameraFollow {
+///             max_yaw_speed: Some(1.5),
+///             ..RtsCameraFollow::behind(hero)
+///         });
+///     }
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct RtsCameraFollow {
+    /// The entity to follow.
+    pub target: Entity,
+    /// Offset from the target's position, in world space. Note that the camera will still follow
+    /// the ground, so the Y offset only has an effect if there is no ground.
+    /// Defaults to `Vec3::ZERO`.
+    pub offset: Vec3,
+    /// Whether to snap to the target's position every frame instead of smoothly following it.
+    /// Defaults to `false`.
+    pub snap: bool,
+    /// Whether to stop following (by removing this component) when the camera is panned with
+    /// `RtsCameraControls`.
+    /// Defaults to `true`.
+    pub break_on_pan: bool,
+    /// Whether to rotate `target_focus` to face the same way as the target (around the normal of
+    /// `RtsCamera.ground_plane`), so the camera looks over its shoulder. The rotation is smoothed
+    /// by `RtsCamera.rotate_smoothness` and limited by `max_yaw_speed`.
+    /// Defaults to `false`.
+    pub align_yaw: bool,
+    /// How far in radians to rotate the camera from the target's facing when `align_yaw` is
+    /// enabled, where positive values rotate counterclockwise when looking down, e.g. `PI` to look
+    /// at the target's front.
+    /// Defaults to `0.0`.
+    pub yaw_offset: f32,
+    /// The fastest `target_focus` will rotate to keep up with the target when `align_yaw` is
+    /// enabled, in radians per second, or `None` for no limit.
+    /// Defaults to `None`.
+    pub max_yaw_speed: Option<f32>,
+    /// How close (as a fraction of the viewport size) the target can get to the edge of the
+    /// screen before the camera pans to keep up, or `None` to keep the target centered. For
+    /// example, with `0.25` the target can move freely within the middle half of the screen.
+    /// Needs a `Camera` on the same entity.
+    /// Defaults to `None`.
+    pub edge_margin: Option<f32>,
+}
+
+impl RtsCameraFollow {
+    /// Creates a follow for `target` with default settings.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec3::ZERO,
+            snap: false,
+            break_on_pan: true,
+            align_yaw: false,
+            yaw_offset: 0.0,
+            max_yaw_speed: None,
+            edge_margin: None,
+        }
+    }
+
+    /// Creates a follow for `target` that only pans once the target is within `edge_margin` of
+    /// the edge of the screen.
+    pub fn soft(target: Entity, edge_margin: f32) -> Self {
+        Self {
+            edge_margin: Some(edge_margin),
+            ..Self::new(target)
+        }
+    }
+
+    /// Creates a follow for `target` that also keeps the camera behind it (see `align_yaw`).
+    pub fn behind(target: Entity) -> Self {
+        Self {
+            align_yaw: true,
+            ..Self::new(target)
+        }
+    }
+}
+
+/// Optional component that adds a custom filter to all ray casts made for the `RtsCamera` on the
+/// same entity (ground following, obstacle avoidance, and grab pan). The filter is applied in
+/// addition to the `Ground` and `CameraObstacle` markers, so an entity is only hit if it has the
+/// relevant marker *and* the filter returns `true`. Useful when different cameras should treat
+/// different entities as ground. Also sets which `GroundLayers` the camera follows.
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::RtsCameraRayFilter;
+/// # let destructible_entity = Entity::PLACEHOLDER;
+/// // Ignore a specific entity
+/// let filter = RtsCameraRayFilter::new(move |entity| entity != destructible_entity);
+/// ```
+#[derive(Component, Clone)]
+pub struct RtsCameraRayFilter {
+    filter: Arc<dyn Fn(Entity) -> bool + Send + Sync>,
+    ground_layers: GroundLayers,
+}
+
+impl RtsCameraRayFilter {
+    /// Creates a new filter from a predicate. Ray casts will ignore any entity for which the
+    /// predicate returns `false`.
+    pub fn new(filter: impl Fn(Entity) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            filter: Arc::new(filter),
+            ground_layers: GroundLayers::default(),
+        }
+    }
+
+    /// Sets which `GroundLayers` the camera follows.
+    /// Defaults to `GroundLayers::layer(0)`.
+    pub fn with_ground_layers(mut self, ground_layers: GroundLayers) -> Self {
+        self.ground_layers = ground_layers;
+        self
+    }
+
+    /// Returns which `GroundLayers` the camera follows.
+    pub fn ground_layers(&self) -> GroundLayers {
+        self.ground_layers
+    }
+
+    /// Returns whether ray casts should be allowed to hit `entity`.
+    pub fn test(&self, entity: Entity) -> bool {
+        (self.filter)(entity)
+    }
+}
+
+impl std::fmt::Debug for RtsCameraRayFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RtsCameraRayFilter")
+            .field("ground_layers", &self.ground_layers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Marks an `RtsCamera` with `RtsCameraInitMode::WaitForGround` that hasn't found ground yet.
+#[derive(Component, Copy, Clone, Debug)]
+struct AwaitingGround {
+    /// When the camera was added.
+    since: Duration,
+}
+
+fn initialize(
+    mut cam_q: Query<(Entity, &mut RtsCamera), Added<RtsCamera>>,
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut cam) in cam_q.iter_mut() {
         // Snap to targets when RtsCamera is added. Note that we snap whole transform, not just XZ
         // translation like snap_to system.
         cam.zoom = cam.target_zoom;
         cam.focus = cam.target_focus;
         cam.angle = cam.min_angle;
         cam.target_angle = cam.min_angle;
+        if let RtsCameraInitMode::WaitForGround { .. } = cam.init_mode {
+            commands.entity(entity).insert(AwaitingGround {
+                since: time.elapsed(),
+            });
+        }
     }
 }
 
-fn follow_ground(
-    mut cam_q: Query<&mut RtsCamera>,
-    ground_q: Query<Entity, With<Ground>>,
-    mut ray_cast: MeshRayCast,
+/// Snaps cameras waiting for ground once it has been found below them, or they give up waiting.
+fn finish_initialize(
+    mut cam_q: Query<(Entity, &mut RtsCamera, &AwaitingGround)>,
+    cache: Res<RtsCameraGroundCache>,
+    mut commands: Commands,
+    time: Res<Time<Real>>,
 ) {
-    for mut cam in cam_q.iter_mut() {
-        let ray_start = Vec3::new(
-            cam.target_focus.translation.x,
-            cam.target_focus.translation.y + cam.height_max,
-            cam.target_focus.translation.z,
+    for (entity, mut cam, awaiting) in cam_q.iter_mut() {
+        // The cache only has an entry once a ray cast has hit the ground
+        let timeout = match cam.init_mode {
+            RtsCameraInitMode::WaitForGround { timeout } => Some(timeout),
+            RtsCameraInitMode::Immediate => None,
+        };
+        let timed_out =
+            timeout.is_none_or(|timeout| time.elapsed().saturating_sub(awaiting.since) >= timeout);
+        if !cache.entries.contains_key(&entity) && !timed_out {
+            continue;
+        }
+        // Like `initialize`, snap the height too, as there's nothing to smooth from
+        cam.focus = cam.target_focus;
+        cam.zoom = cam.target_zoom;
+        cam.angle = cam.target_angle;
+        commands.entity(entity).remove::<AwaitingGround>();
+    }
+}
+
+fn follow_entity(
+    mut cam_q: Query<(
+        Entity,
+        &mut RtsCamera,
+        &RtsCameraFollow,
+        Option<&Camera>,
+        &GlobalTransform,
+    )>,
+    target_q: Query<&GlobalTransform>,
+    mut commands: Commands,
+    time: CameraTime,
+) {
+    for (entity, mut cam, follow, camera, cam_tfm) in cam_q.iter_mut() {
+        let Ok(target_tfm) = target_q.get(follow.target) else {
+            commands.entity(entity).remove::<RtsCameraFollow>();
+            continue;
+        };
+        let mut target = target_tfm.translation() + follow.offset;
+        if let (Some(margin), Some(camera)) = (follow.edge_margin, camera) {
+            match soft_follow_shift(&cam, camera, cam_tfm, target, margin) {
+                Some(shift) => target = cam.focus.translation + shift,
+                // Still inside the margin, so the camera stays put
+                None => target = cam.target_focus.translation,
+            }
+        }
+        if cam.target_focus.translation != target {
+            cam.target_focus.translation = target;
+        }
+        if follow.snap {
+            cam.snap.translation = true;
+        }
+
+        if !follow.align_yaw {
+            continue;
+        }
+        let up = cam.ground_plane.normal;
+        let Ok(facing) = Dir3::new(Vec3::from(target_tfm.forward()).reject_from_normalized(*up))
+        else {
+            // Facing straight up or down, so there's no yaw to follow
+            continue;
+        };
+        let mut rotation = Quat::from_axis_angle(*up, follow.yaw_offset)
+            * Transform::IDENTITY.looking_to(facing, up).rotation;
+        if let Some(max_yaw_speed) = follow.max_yaw_speed {
+            let angle = cam.target_focus.rotation.angle_between(rotation);
+            let max_angle = max_yaw_speed * time.delta_secs();
+            if angle > max_angle {
+                rotation = cam.target_focus.rotation.slerp(rotation, max_angle / angle);
+            }
+        }
+        if cam.target_focus.rotation != rotation {
+            cam.target_focus.rotation = rotation;
+        }
+    }
+}
+
+/// Returns how far the focus of a camera has to move for `target` to be back inside the area of
+/// the screen `margin` away from the edges, or `None` if it already is. Moves the focus all the way
+/// to `target` if it's behind the camera.
+fn soft_follow_shift(
+    cam: &RtsCamera,
+    camera: &Camera,
+    cam_tfm: &GlobalTransform,
+    target: Vec3,
+    margin: f32,
+) -> Option<Vec3> {
+    let center_on_target = Some(target - cam.focus.translation);
+    let (Some(size), Ok(position)) = (
+        camera.logical_viewport_size(),
+        camera.world_to_viewport(cam_tfm, target),
+    ) else {
+        return center_on_target;
+    };
+    let margin = size * margin.clamp(0.0, 0.5);
+    let clamped = position.clamp(margin, size - margin);
+    if clamped == position {
+        return None;
+    }
+    // Where on the target's level the clamped position is, which is where the target should be
+    let Ok(ray) = camera.viewport_to_world(cam_tfm, clamped) else {
+        return center_on_target;
+    };
+    let Some(distance) =
+        ray.intersect_plane(target, InfinitePlane3d::new(*cam.ground_plane.normal))
+    else {
+        return center_on_target;
+    };
+    Some(target - ray.get_point(distance))
+}
+
+fn jump_to(
+    mut events: EventReader<RtsCameraJumpTo>,
+    mut cam_q: Query<(Entity, &mut RtsCamera, Option<&RtsCameraFollow>)>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        for (entity, mut cam, follow) in cam_q.iter_mut() {
+            if event.camera.is_some_and(|target| target != entity) {
+                continue;
+            }
+            cam.target_focus.translation = event.position;
+            if let Some(zoom) = event.zoom {
+                cam.target_zoom = zoom.clamp(0.0, 1.0);
+            }
+            if event.instant {
+                cam.snap.translation = true;
+                cam.snap.zoom |= event.zoom.is_some();
+            }
+            cam.flight = None;
+            if follow.is_some_and(|follow| follow.break_on_pan) {
+                commands.entity(entity).remove::<RtsCameraFollow>();
+            }
+        }
+    }
+}
+
+fn follow_ground<B: GroundBackend>(
+    mut cam_q: Query<(Entity, &mut RtsCamera, Option<&RtsCameraRayFilter>)>,
+    ground: RtsCameraGround<B>,
+    mut cache: ResMut<RtsCameraGroundCache>,
+    mut removed: RemovedComponents<RtsCamera>,
+    time: Res<Time<Real>>,
+    mut fallback_events: EventWriter<RtsCameraGroundFallback>,
+    mut fallen_back: Local<EntityHashSet>,
+) {
+    for entity in removed.read() {
+        cache.entries.remove(&entity);
+        fallen_back.remove(&entity);
+    }
+    let now = time.elapsed();
+    // Reuse the last hit for cameras that haven't moved far enough, and cast all the other rays
+    // together, rather than one camera at a time
+    let mut cached = Vec::new();
+    let mut entities = Vec::new();
+    let mut rays = Vec::new();
+    for (entity, cam, ray_filter) in cam_q.iter() {
+        let focus = cam.world_to_plane(cam.target_focus.translation).xz();
+        let entry = cache.entries.get(&entity).filter(|entry| {
+            entry.focus.distance(focus) <= cam.ground_recast_distance
+                && cam
+                    .ground_refresh_interval
+                    .is_none_or(|interval| now.saturating_sub(entry.time) < interval)
+        });
+        if let Some(entry) = entry {
+            cached.push((entity, entry.hit));
+            continue;
+        }
+        let ray_start = cam.target_focus.translation + cam.ground_plane.normal * cam.height_max;
+        let offsets = match cam.ground_sampling {
+            GroundSampling::Single => vec![Vec2::ZERO],
+            GroundSampling::Average { spread } | GroundSampling::Median { spread } => {
+                let distance = spread * cam.height_at_zoom(cam.target_zoom);
+                [Vec2::ZERO, Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y]
+                    .map(|dir| dir * distance)
+                    .to_vec()
+            }
+        };
+        entities.push((entity, rays.len()..rays.len() + offsets.len()));
+        rays.extend(offsets.into_iter().map(|offset| {
+            let start = ray_start + cam.plane_to_world(Vec3::new(offset.x, 0.0, offset.y));
+            (Ray3d::new(start, -cam.ground_plane.normal), ray_filter)
+        }));
+    }
+    // Nothing to cast if every camera could reuse its last hit
+    let ray_hits = if rays.is_empty() {
+        Vec::new()
+    } else {
+        ground.cast_rays(&rays)
+    };
+    let (entities, hits): (Vec<_>, Vec<_>) = entities
+        .into_iter()
+        .map(|(entity, range)| {
+            let hit = cam_q
+                .get(entity)
+                .ok()
+                .and_then(|(_, cam, _)| combine_ground_hits(cam, &ray_hits[range]));
+            (entity, hit)
+        })
+        .unzip();
+    for (&entity, hit) in entities.iter().zip(&hits) {
+        let (Some(hit), Ok((_, cam, _))) = (hit, cam_q.get(entity)) else {
+            continue;
+        };
+        cache.entries.insert(
+            entity,
+            GroundCacheEntry {
+                focus: cam.world_to_plane(cam.target_focus.translation).xz(),
+                hit: *hit,
+                time: now,
+            },
+        );
+    }
+
+    let hits = entities
+        .into_iter()
+        .zip(hits)
+        .chain(cached.into_iter().map(|(entity, hit)| (entity, Some(hit))));
+    for (entity, hit) in hits {
+        let Ok((_, mut cam, _)) = cam_q.get_mut(entity) else {
+            continue;
+        };
+        let hit = match (hit, cam.fallback_ground) {
+            (Some(hit), _) => {
+                fallen_back.remove(&entity);
+                hit
+            }
+            (None, Some(height)) => {
+                let mut point = cam.world_to_plane(cam.target_focus.translation);
+                point.y = height;
+                let point = cam.plane_to_world(point);
+                if fallen_back.insert(entity) {
+                    debug!("No ground below camera {entity}, falling back to the ground plane");
+                    fallback_events.send(RtsCameraGroundFallback {
+                        camera: entity,
+                        point,
+                    });
+                }
+                point
+            }
+            (None, None) => continue,
+        };
+        let hit = cam.clamp_ground_height(hit);
+        if cam.ground_point != hit {
+            cam.ground_point = hit;
+        }
+        let step = cam.world_to_plane(hit - cam.target_focus.translation).y;
+        if step.abs() > cam.ground_step_tolerance {
+            cam.target_focus.translation = cam.with_height_of(cam.target_focus.translation, hit);
+        }
+    }
+}
+
+/// Combines the hits of the rays cast for `GroundSampling` into a single point on the ground,
+/// where the first hit is the one directly below the focus.
+fn combine_ground_hits(cam: &RtsCamera, hits: &[Option<Vec3>]) -> Option<Vec3> {
+    let center = hits.first().copied().flatten();
+    let mut heights = hits
+        .iter()
+        .flatten()
+        .map(|hit| cam.world_to_plane(*hit).y)
+        .collect::<Vec<_>>();
+    if heights.is_empty() {
+        return None;
+    }
+    let height = match cam.ground_sampling {
+        GroundSampling::Single => return center,
+        GroundSampling::Average { .. } => heights.iter().sum::<f32>() / heights.len() as f32,
+        GroundSampling::Median { .. } => {
+            heights.sort_by(f32::total_cmp);
+            let mid = heights.len() / 2;
+            if heights.len() % 2 == 0 {
+                (heights[mid - 1] + heights[mid]) / 2.0
+            } else {
+                heights[mid]
+            }
+        }
+    };
+    let mut center = cam.world_to_plane(center.unwrap_or(cam.target_focus.translation));
+    center.y = height;
+    Some(cam.plane_to_world(center))
+}
+
+fn keep_ground_clearance<B: GroundBackend>(
+    mut cam_q: Query<(
+        Entity,
+        &mut RtsCamera,
+        Option<&Projection>,
+        Option<&RtsCameraRayFilter>,
+    )>,
+    ground: RtsCameraGround<B>,
+    time: CameraTime,
+) {
+    let mut entities = Vec::new();
+    let mut rays = Vec::new();
+    for (entity, mut cam, projection, ray_filter) in cam_q.iter_mut() {
+        if cam.ground_clearance.is_none() || !cam.zoom_changes_height(projection) {
+            if cam.ground_clearance_zoom != 1.0 {
+                cam.ground_clearance_zoom = 1.0;
+            }
+            continue;
+        }
+        // Zooming moves the camera along the line from the focus to the camera when fully zoomed
+        // out, so cast back along it to find the furthest ground the camera has to clear
+        let far = cam.focus.translation + cam.offset_at(&cam.focus, 0.0, cam.angle);
+        let Ok(direction) = Dir3::new(cam.focus.translation - far) else {
+            continue;
+        };
+        entities.push((entity, far));
+        rays.push((Ray3d::new(far, direction), ray_filter));
+    }
+    if rays.is_empty() {
+        return;
+    }
+    let hits = ground.cast_rays(&rays);
+    for ((entity, far), hit) in entities.into_iter().zip(hits) {
+        let Ok((_, mut cam, _, _)) = cam_q.get_mut(entity) else {
+            continue;
+        };
+        let clearance = cam.ground_clearance.unwrap_or_default();
+        let focus_height = cam.world_to_plane(cam.focus.translation).y;
+        // Hits past the focus are ground in front of the camera, which it doesn't need to clear
+        let length = cam.focus.translation.distance(far);
+        let min_height = hit
+            .filter(|hit| hit.distance(far) <= length)
+            .map_or(0.0, |hit| {
+                cam.world_to_plane(hit).y - focus_height + clearance
+            });
+        let limit = if min_height <= cam.height_min {
+            1.0
+        } else {
+            cam.zoom_at_height(min_height)
+        };
+        // Smoothed like zoom, so the camera eases out of the way rather than jumping
+        let mut clearance_zoom = cam.ground_clearance_zoom.lerp(
+            limit,
+            1.0 - cam.zoom_smoothness.powi(7).powf(time.delta_secs()),
         );
-        if let Some(hit1) = cast_ray(ray_start, Dir3::NEG_Y, &mut ray_cast, &|entity| {
-            ground_q.get(entity).is_ok()
-        }) {
-            cam.target_focus.translation.y = hit1.point.y;
+        if (clearance_zoom - limit).abs() < SETTLE_THRESHOLD {
+            clearance_zoom = limit;
+        }
+        if cam.ground_clearance_zoom != clearance_zoom {
+            cam.ground_clearance_zoom = clearance_zoom;
         }
     }
 }
 
 fn snap_to_target(mut cam_q: Query<&mut RtsCamera>) {
-    // When snapping in a top down camera, only the XZ should be snapped. The Y coord is controlled
-    // by zoom and that should remain smoothed, as should rotation.
     for mut cam in cam_q.iter_mut() {
-        if cam.snap {
-            cam.focus.translation.x = cam.target_focus.translation.x;
-            cam.focus.translation.z = cam.target_focus.translation.z;
-            cam.snap = false;
+        if cam.snap == RtsCameraSnap::NONE {
+            continue;
+        }
+        // Only the translation along the ground plane is snapped. The height follows the ground
+        // and that should remain smoothed.
+        if cam.snap.translation {
+            cam.focus.translation =
+                cam.with_height_of(cam.target_focus.translation, cam.focus.translation);
+        }
+        if cam.snap.rotation {
+            cam.focus.rotation = cam.target_focus.rotation;
+        }
+        if cam.snap.zoom {
+            cam.zoom = cam.target_zoom;
+        }
+        if cam.snap.angle {
+            cam.angle = cam.target_angle;
         }
+        cam.snap = RtsCameraSnap::NONE;
     }
 }
 
 fn dynamic_angle(mut query: Query<&mut RtsCamera>) {
     for mut cam in query.iter_mut().filter(|cam| cam.dynamic_angle) {
-        cam.target_angle = cam
-            .min_angle
-            .lerp(MAX_ANGLE, ease_in_circular(cam.target_zoom));
+        let target_angle = cam.angle_at_zoom(cam.target_zoom);
+        if cam.target_angle != target_angle {
+            cam.target_angle = target_angle;
+        }
     }
 }
 
-fn move_towards_target(mut cam_q: Query<&mut RtsCamera>, time: Res<Time<Real>>) {
+fn move_towards_target(mut cam_q: Query<&mut RtsCamera>, time: CameraTime) {
     for mut cam in cam_q.iter_mut() {
-        cam.focus.translation = cam.focus.translation.lerp(
-            cam.target_focus.translation,
-            1.0 - cam.smoothness.powi(7).powf(time.delta_secs()),
+        // Don't touch cameras that have already arrived, so they aren't marked as changed
+        if cam.focus.translation == cam.target_focus.translation
+            && cam.focus.rotation == cam.target_focus.rotation
+            && cam.zoom == cam.target_zoom
+            && cam.angle == cam.target_angle
+        {
+            continue;
+        }
+        // Horizontal and vertical movement are smoothed separately, relative to the ground plane
+        let focus = cam.world_to_plane(cam.focus.translation);
+        let target_focus = cam.world_to_plane(cam.target_focus.translation);
+        let focus_xz = focus.xz().lerp(
+            target_focus.xz(),
+            1.0 - cam.pan_smoothness.powi(7).powf(time.delta_secs()),
+        );
+        let mut focus_y = focus.y.lerp(
+            target_focus.y,
+            1.0 - cam.height_smoothness.powi(7).powf(time.delta_secs()),
         );
+        if let Some(max_vertical_speed) = cam.max_vertical_speed {
+            let max_step = max_vertical_speed * time.delta_secs();
+            focus_y = focus.y + (focus_y - focus.y).clamp(-max_step, max_step);
+        }
+        cam.focus.translation = cam.plane_to_world(Vec3::new(focus_xz.x, focus_y, focus_xz.y));
         cam.focus.rotation = cam.focus.rotation.lerp(
             cam.target_focus.rotation,
-            1.0 - cam.smoothness.powi(7).powf(time.delta_secs()),
+            1.0 - cam.rotate_smoothness.powi(7).powf(time.delta_secs()),
         );
         cam.zoom = cam.zoom.lerp(
             cam.target_zoom,
-            1.0 - cam.smoothness.powi(7).powf(time.delta_secs()),
+            1.0 - cam.zoom_smoothness.powi(7).powf(time.delta_secs()),
         );
         cam.angle = cam.angle.lerp(
             cam.target_angle,
-            1.0 - cam.smoothness.powi(7).powf(time.delta_secs()),
+            1.0 - cam.angle_smoothness.powi(7).powf(time.delta_secs()),
         );
+        // Smoothing only ever gets closer to the target without reaching it, so finish the move
+        // once the difference is imperceptible, allowing the camera to become idle
+        if cam.focus.translation.distance(cam.target_focus.translation) < SETTLE_THRESHOLD {
+            cam.focus.translation = cam.target_focus.translation;
+        }
+        if cam.focus.rotation.angle_between(cam.target_focus.rotation) < SETTLE_THRESHOLD {
+            cam.focus.rotation = cam.target_focus.rotation;
+        }
+        if (cam.zoom - cam.target_zoom).abs() < SETTLE_THRESHOLD {
+            cam.zoom = cam.target_zoom;
+        }
+        if (cam.angle - cam.target_angle).abs() < SETTLE_THRESHOLD {
+            cam.angle = cam.target_angle;
+        }
     }
 }
 
-fn apply_bounds(mut cam_q: Query<&mut RtsCamera>) {
+fn fly(
+    mut cam_q: Query<(Entity, &mut RtsCamera)>,
+    time: CameraTime,
+    mut move_complete: EventWriter<CameraMoveComplete>,
+) {
+    for (entity, mut cam) in cam_q.iter_mut() {
+        let Some(mut flight) = cam.flight else {
+            continue;
+        };
+        flight.elapsed += time.delta();
+        let t = if flight.duration.is_zero() {
+            1.0
+        } else {
+            (flight.elapsed.as_secs_f32() / flight.duration.as_secs_f32()).min(1.0)
+        };
+        let t = EasingCurve::new(0.0, 1.0, flight.easing).sample_clamped(t);
+
+        // Move the focus directly, bypassing smoothing. The height is left alone so it can follow
+        // the ground as usual.
+        let translation = flight.from.translation.lerp(flight.to.translation, t);
+        let rotation = flight.from.rotation.slerp(flight.to.rotation, t);
+        let zoom = flight.from_zoom.lerp(flight.to_zoom, t);
+        cam.target_focus.translation =
+            cam.with_height_of(translation, cam.target_focus.translation);
+        cam.target_focus.rotation = rotation;
+        cam.focus.translation = cam.with_height_of(translation, cam.focus.translation);
+        cam.focus.rotation = rotation;
+        cam.target_zoom = zoom;
+        cam.zoom = zoom;
+
+        if flight.elapsed >= flight.duration {
+            cam.flight = None;
+            move_complete.send(CameraMoveComplete { camera: entity });
+        } else {
+            cam.flight = Some(flight);
+        }
+    }
+}
+
+fn keep_upright(mut cam_q: Query<&mut RtsCamera>) {
     for mut cam in cam_q.iter_mut() {
-        let closest_point = cam.bounds.closest_point(Vec2::new(
-            cam.target_focus.translation.x,
-            -cam.target_focus.translation.z,
-        ));
-        let closest_point = Vec3::new(
-            closest_point.x,
-            cam.target_focus.translation.y,
-            -closest_point.y,
+        if let Some(rotation) = cam.upright_rotation(cam.target_focus.rotation) {
+            cam.target_focus.rotation = rotation;
+        }
+        if let Some(rotation) = cam.upright_rotation(cam.focus.rotation) {
+            cam.focus.rotation = rotation;
+        }
+    }
+}
+
+fn apply_bounds(
+    mut cam_q: Query<(Entity, &mut RtsCamera, Option<&Projection>)>,
+    mut hit_bounds: EventWriter<RtsCameraHitBounds>,
+) {
+    for (entity, mut cam, projection) in cam_q.iter_mut() {
+        // Bounds are on the ground plane, with +Y being -Z
+        let focus = cam.to_bounds(cam.target_focus.translation);
+        let offset = match (cam.bounds_mode, projection) {
+            (CameraBoundsMode::Focus, _) => cam.bounds.closest_point(focus) - focus,
+            (CameraBoundsMode::View, _) | (CameraBoundsMode::Frustum, None) => {
+                let camera_height = cam.height_at_zoom(cam.target_zoom);
+                let camera_offset =
+                    cam.target_focus.back() * camera_height * cam.target_angle.tan();
+                cam.bounds
+                    .fit_points(&[focus, focus + cam.to_bounds(camera_offset)])
+            }
+            (CameraBoundsMode::Frustum, Some(projection)) => {
+                let height_zoom = cam.height_zoom(cam.target_zoom, Some(projection));
+                let cam_tfm =
+                    cam.camera_transform(&cam.target_focus, height_zoom, cam.target_angle);
+                // An orthographic projection's area is for the current scale, which may not have
+                // caught up to the target zoom
+                let area_scale = match projection {
+                    Projection::Orthographic(ortho) => cam
+                        .ortho_scale_at_zoom(cam.target_zoom, projection)
+                        .map_or(1.0, |target_scale| target_scale / ortho.scale),
+                    Projection::Perspective(_) => 1.0,
+                };
+                // Likewise for a perspective projection's field of view
+                let mut target_projection = projection.clone();
+                if let (Projection::Perspective(persp), Some(fov)) = (
+                    &mut target_projection,
+                    cam.fov_at_zoom(cam.target_zoom, projection),
+                ) {
+                    persp.fov = fov;
+                }
+                let corners = frustum_footprint(
+                    &cam_tfm,
+                    &target_projection,
+                    area_scale,
+                    cam.target_focus.translation,
+                    cam.ground_plane.normal,
+                );
+                let mut points = vec![focus];
+                points.extend(corners.map(|corner| cam.to_bounds(corner)));
+                cam.bounds.fit_points(&points)
+            }
+        };
+        if offset != Vec2::ZERO {
+            let offset = cam.plane_to_world(Vec3::new(offset.x, 0.0, -offset.y));
+            cam.target_focus.translation += offset;
+            hit_bounds.send(RtsCameraHitBounds { camera: entity });
+        }
+    }
+}
+
+/// Returns the points where the corners of the view of a camera at `cam_tfm` meet the plane
+/// through `ground` facing `up`, in the order bottom left, bottom right, top right, top left (on
+/// screen). `area_scale` scales the area of an orthographic projection, for when its scale hasn't
+/// caught up yet. Corners above the horizon are limited to the far plane.
+fn frustum_footprint(
+    cam_tfm: &Transform,
+    projection: &Projection,
+    area_scale: f32,
+    ground: Vec3,
+    up: Dir3,
+) -> [Vec3; 4] {
+    // The (view space) origin and direction of the ray through each corner of the view
+    let (corners, far) = match projection {
+        Projection::Perspective(persp) => {
+            let half_height = (persp.fov / 2.0).tan();
+            let half_width = half_height * persp.aspect_ratio;
+            let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].map(|(x, y)| {
+                (
+                    Vec3::ZERO,
+                    Vec3::new(x * half_width, y * half_height, -1.0).normalize(),
+                )
+            });
+            (corners, persp.far)
+        }
+        Projection::Orthographic(ortho) => {
+            let area = Rect::from_corners(ortho.area.min * area_scale, ortho.area.max * area_scale);
+            let corners = [
+                area.min,
+                Vec2::new(area.max.x, area.min.y),
+                area.max,
+                Vec2::new(area.min.x, area.max.y),
+            ]
+            .map(|corner| (corner.extend(0.0), Vec3::NEG_Z));
+            (corners, ortho.far)
+        }
+    };
+
+    corners.map(|(corner_origin, corner_dir)| {
+        let ray_origin = cam_tfm.transform_point(corner_origin);
+        let ray_dir = cam_tfm.rotation * corner_dir;
+        let height = (ray_origin - ground).dot(*up);
+        let descent = -ray_dir.dot(*up);
+        let distance = if descent > 0.0 {
+            (height / descent).min(far)
+        } else {
+            far
+        };
+        ray_origin + ray_dir * distance
+    })
+}
+
+fn update_frustum_footprint(
+    mut cam_q: Query<(
+        &mut RtsCameraFrustumFootprint,
+        &RtsCamera,
+        &Transform,
+        &Projection,
+    )>,
+) {
+    for (mut footprint, cam, cam_tfm, projection) in cam_q.iter_mut() {
+        let corners = frustum_footprint(
+            cam_tfm,
+            projection,
+            1.0,
+            cam.focus.translation,
+            cam.ground_plane.normal,
         );
-        cam.target_focus.translation = closest_point;
+        if footprint.corners != corners {
+            footprint.corners = corners;
+        }
     }
 }
 
-fn update_camera_transform(mut cam_q: Query<(&mut Transform, &RtsCamera)>) {
-    for (mut tfm, cam) in cam_q.iter_mut() {
-        let rotation = Quat::from_rotation_x(cam.angle - 90f32.to_radians());
-        let camera_height = cam.height_max.lerp(cam.height_min, cam.zoom);
-        let camera_offset = camera_height * cam.angle.tan();
+fn update_camera_transform(
+    mut cam_q: Query<(
+        Entity,
+        &mut Transform,
+        &mut RtsCamera,
+        Option<&mut Projection>,
+    )>,
+    parent_q: Query<&Parent>,
+    global_tfm_q: Query<&GlobalTransform>,
+    // Cameras whose transform was being controlled by something else last frame
+    mut external: Local<EntityHashSet>,
+) {
+    external.retain(|entity| cam_q.contains(*entity));
+    for (entity, mut tfm, mut cam, projection) in cam_q.iter_mut() {
+        if cam.external_transform_control {
+            external.insert(entity);
+            continue;
+        }
+        if external.remove(&entity) {
+            // Whatever moved the camera wrote its local transform, so resync from where it is in
+            // the world. Its own `GlobalTransform` hasn't been propagated yet this frame, so
+            // combine the parent's instead
+            let parent_tfm = parent_q
+                .get(entity)
+                .ok()
+                .and_then(|parent| global_tfm_q.get(parent.get()).ok());
+            let world_tfm = match parent_tfm {
+                Some(parent_tfm) => parent_tfm.mul_transform(*tfm).compute_transform(),
+                None => *tfm,
+            };
+            cam.sync_from_transform(&world_tfm);
+        }
+        let height_zoom = cam.height_zoom(cam.zoom, projection.as_deref());
+
+        // Only write the transform if it's actually different, to avoid triggering change
+        // detection (and transform propagation) when the camera is idle
+        let new_tfm = cam
+            .camera_transform(&cam.focus, height_zoom, cam.angle)
+            .with_scale(tfm.scale);
+        tfm.set_if_neq(new_tfm);
 
-        tfm.rotation = cam.focus.rotation * rotation;
-        tfm.translation =
-            cam.focus.translation + (Vec3::Y * camera_height) + (cam.focus.back() * camera_offset);
+        let Some(mut projection) = projection else {
+            continue;
+        };
+        if let Some(fov) = cam.fov_at_zoom(cam.zoom, &projection) {
+            // Same as above, only write the field of view if it changed
+            if let Projection::Perspective(ref persp) = *projection {
+                if persp.fov != fov {
+                    if let Projection::Perspective(ref mut persp) = *projection {
+                        persp.fov = fov;
+                    }
+                }
+            }
+        }
+        let Some(scale) = cam.ortho_scale_at_zoom(cam.zoom, &projection) else {
+            continue;
+        };
+        // Same as above, only write the scale if it changed
+        if let Projection::Orthographic(ref ortho) = *projection {
+            if ortho.scale != scale {
+                if let Projection::Orthographic(ref mut ortho) = *projection {
+                    ortho.scale = scale;
+                }
+            }
+        }
     }
 }
 
-fn cast_ray<'a>(
-    origin: Vec3,
-    dir: Dir3,
-    ray_cast: &'a mut MeshRayCast<'_, '_>,
-    filter: &'a dyn Fn(Entity) -> bool,
-) -> Option<&'a RayMeshHit> {
-    let ray1 = Ray3d::new(origin, dir);
-    let hits1 = ray_cast.cast_ray(
-        ray1,
-        &RayCastSettings {
-            filter,
-            ..default()
-        },
-    );
-    hits1.first().map(|(_, hit)| hit)
+fn avoid_ground_clipping<B: GroundBackend>(
+    mut cam_q: Query<(
+        Entity,
+        &mut Transform,
+        &RtsCamera,
+        Option<&RtsCameraRayFilter>,
+    )>,
+    ground: RtsCameraGround<B>,
+) {
+    let mut entities = Vec::new();
+    let mut rays = Vec::new();
+    for (entity, tfm, cam, ray_filter) in cam_q
+        .iter()
+        .filter(|(_, _, cam, _)| !cam.external_transform_control)
+    {
+        let Some(margin) = cam.near_clip_margin else {
+            continue;
+        };
+        if let Ok(dir) = Dir3::new(tfm.translation - cam.focus.translation) {
+            // Start just past the focus, which is on the ground itself
+            let ray = Ray3d::new(cam.focus.translation + dir * margin, dir);
+            entities.push((entity, ray));
+            rays.push((ray, ray_filter));
+        }
+    }
+    if rays.is_empty() {
+        return;
+    }
+    let hits = ground.cast_rays(&rays);
+    for ((entity, ray), hit) in entities.into_iter().zip(hits) {
+        let (Some(hit), Ok((_, mut tfm, cam, _))) = (hit, cam_q.get_mut(entity)) else {
+            continue;
+        };
+        let margin = cam.near_clip_margin.unwrap_or_default();
+        let hit_distance = hit.distance(ray.origin);
+        let distance = tfm.translation.distance(ray.origin);
+        if hit_distance < distance + margin {
+            let new_distance = (hit_distance - margin).max(0.0);
+            tfm.translation = ray.get_point(new_distance);
+        }
+    }
+}
+
+fn avoid_obstacles(
+    mut cam_q: Query<(
+        Entity,
+        &mut Transform,
+        &RtsCamera,
+        Option<&RtsCameraRayFilter>,
+    )>,
+    ray_cast: BatchRayCast<With<CameraObstacle>>,
+) {
+    if ray_cast.is_empty() {
+        return;
+    }
+    let mut entities = Vec::new();
+    let mut rays = Vec::new();
+    let mut filters = Vec::new();
+    for (entity, tfm, cam, ray_filter) in cam_q.iter().filter(|(_, _, cam, _)| {
+        cam.obstacle_avoidance == ObstacleAvoidance::PullIn && !cam.external_transform_control
+    }) {
+        if let Ok(dir) = Dir3::new(tfm.translation - cam.focus.translation) {
+            entities.push(entity);
+            rays.push(Ray3d::new(cam.focus.translation, dir));
+            filters.push(ray_filter);
+        }
+    }
+    let hits = ray_cast.cast_rays(&rays, |i, entity| {
+        filters[i].is_none_or(|filter| filter.test(entity))
+    });
+    for ((entity, ray), hit) in entities.into_iter().zip(rays).zip(hits) {
+        let (Some(hit), Ok((_, mut tfm, cam, _))) = (hit, cam_q.get_mut(entity)) else {
+            continue;
+        };
+        let distance = tfm.translation.distance(cam.focus.translation);
+        if hit.distance < distance + cam.obstacle_clearance {
+            let new_distance = (hit.distance - cam.obstacle_clearance).max(0.0);
+            tfm.translation = ray.get_point(new_distance);
+        }
+    }
+}
+
+/// The most obstacles that will be marked with `OccludingCamera` for a single camera.
+const MAX_OCCLUDERS: usize = 8;
+
+fn mark_occluders(
+    cam_q: Query<(Entity, &Transform, &RtsCamera, Option<&RtsCameraRayFilter>)>,
+    occluding_q: Query<Entity, With<OccludingCamera>>,
+    ray_cast: BatchRayCast<With<CameraObstacle>>,
+    mut commands: Commands,
+    mut occluded_events: EventWriter<RtsCameraOccluded>,
+) {
+    let mut cameras = Vec::new();
+    let mut rays = Vec::new();
+    let mut max_distances = Vec::new();
+    let mut filters = Vec::new();
+    for (entity, tfm, cam, ray_filter) in cam_q
+        .iter()
+        .filter(|(_, _, cam, _)| !cam.external_transform_control)
+    {
+        if cam.obstacle_avoidance != ObstacleAvoidance::MarkOccluders {
+            continue;
+        }
+        let offset = cam.focus.translation - tfm.translation;
+        if let Ok(dir) = Dir3::new(offset) {
+            cameras.push(entity);
+            rays.push(Ray3d::new(tfm.translation, dir));
+            max_distances.push(offset.length());
+            filters.push(ray_filter);
+        }
+    }
+
+    // Each cast only finds the nearest obstacle, so keep casting while ignoring the ones already
+    // found until there's nothing else in the way
+    let mut occluders = EntityHashSet::default();
+    let mut found = vec![Vec::new(); rays.len()];
+    let mut remaining = (0..rays.len()).collect::<Vec<_>>();
+    for _ in 0..MAX_OCCLUDERS {
+        if remaining.is_empty() || ray_cast.is_empty() {
+            break;
+        }
+        let remaining_rays = remaining.iter().map(|&i| rays[i]).collect::<Vec<_>>();
+        let hits = ray_cast.cast_rays_including_hidden(&remaining_rays, |i, entity| {
+            let ray = remaining[i];
+            !found[ray].contains(&entity) && filters[ray].is_none_or(|filter| filter.test(entity))
+        });
+        let mut still_remaining = Vec::new();
+        for (ray, hit) in remaining.iter().copied().zip(hits) {
+            let Some((entity, _)) = hit.filter(|(_, hit)| hit.distance < max_distances[ray]) else {
+                continue;
+            };
+            occluders.insert(entity);
+            found[ray].push(entity);
+            still_remaining.push(ray);
+        }
+        remaining = still_remaining;
+    }
+    for (camera, in_the_way) in cameras.into_iter().zip(found) {
+        if !in_the_way.is_empty() {
+            occluded_events.send(RtsCameraOccluded {
+                camera,
+                occluders: in_the_way,
+            });
+        }
+    }
+
+    for entity in occluding_q.iter() {
+        if !occluders.contains(&entity) {
+            commands.entity(entity).remove::<OccludingCamera>();
+        }
+    }
+    for entity in occluders {
+        if !occluding_q.contains(entity) {
+            commands.entity(entity).insert(OccludingCamera);
+        }
+    }
+}
+
+/// Whether `RtsCameraSystemSet` runs in a fixed timestep schedule. See
+/// `RtsCameraPlugin::with_schedule`.
+#[derive(Resource, Debug)]
+struct CameraTimestep {
+    fixed: bool,
+}
+
+/// The clock used to move the camera, which is `Time<Fixed>` when running in a fixed timestep
+/// schedule and `Time<Real>` otherwise.
+#[derive(SystemParam)]
+struct CameraTime<'w> {
+    timestep: Res<'w, CameraTimestep>,
+    real: Res<'w, Time<Real>>,
+    fixed: Res<'w, Time<Fixed>>,
+}
+
+impl CameraTime<'_> {
+    fn delta(&self) -> Duration {
+        if self.timestep.fixed {
+            self.fixed.delta()
+        } else {
+            self.real.delta()
+        }
+    }
+
+    fn delta_secs(&self) -> f32 {
+        self.delta().as_secs_f32()
+    }
+}
+
+/// The camera's `Transform` after the previous and latest fixed timesteps, which is interpolated
+/// between every frame.
+#[derive(Resource, Debug, Default)]
+struct CameraInterpolation(EntityHashMap<(Transform, Transform)>);
+
+fn record_fixed_transform(
+    cam_q: Query<(Entity, &Transform), With<RtsCamera>>,
+    mut removed: RemovedComponents<RtsCamera>,
+    mut interpolation: ResMut<CameraInterpolation>,
+) {
+    for entity in removed.read() {
+        interpolation.0.remove(&entity);
+    }
+    for (entity, tfm) in cam_q.iter() {
+        let (previous, current) = interpolation.0.entry(entity).or_insert((*tfm, *tfm));
+        *previous = *current;
+        *current = *tfm;
+    }
+}
+
+fn interpolate_transform(
+    mut cam_q: Query<(Entity, &mut Transform, &RtsCamera)>,
+    interpolation: Res<CameraInterpolation>,
+    time: Res<Time<Fixed>>,
+) {
+    let t = time.overstep_fraction();
+    for (entity, mut tfm, _) in cam_q
+        .iter_mut()
+        .filter(|(_, _, cam)| !cam.external_transform_control)
+    {
+        let Some((previous, current)) = interpolation.0.get(&entity) else {
+            continue;
+        };
+        tfm.set_if_neq(Transform {
+            translation: previous.translation.lerp(current.translation, t),
+            rotation: previous.rotation.slerp(current.rotation, t),
+            scale: current.scale,
+        });
+    }
+}
+
+/// Converts a yaw (see `RtsCamera::target_yaw`) to a compass heading in degrees.
+fn heading_degrees(yaw: f32) -> f32 {
+    let heading = (-yaw).to_degrees().rem_euclid(360.0);
+    // Tiny negative yaws round up to exactly 360.0
+    if heading >= 360.0 {
+        0.0
+    } else {
+        heading
+    }
+}
+
+/// How close (in radians, or fractions of the zoom range) a camera has to be to its
+/// `RtsCamera.reset_view` to have finished resetting.
+const RESET_TOLERANCE: f32 = 1e-3;
+
+fn finish_reset(
+    mut cam_q: Query<(Entity, &mut RtsCamera)>,
+    mut reset_complete: EventWriter<RtsCameraResetComplete>,
+) {
+    for (entity, mut cam) in cam_q.iter_mut() {
+        if !cam.resetting {
+            continue;
+        }
+        let reset = cam.reset_view;
+        let north = cam.plane_rotation() * Quat::from_rotation_y(reset.yaw);
+        let zoom = reset.zoom.map(|zoom| zoom.clamp(0.0, 1.0));
+        // Something else turned or zoomed the camera in the meantime
+        let cancelled = cam.target_focus.rotation.angle_between(north) > RESET_TOLERANCE
+            || zoom.is_some_and(|zoom| (cam.target_zoom - zoom).abs() > RESET_TOLERANCE);
+        let arrived = cam.focus.rotation.angle_between(cam.target_focus.rotation)
+            <= RESET_TOLERANCE
+            && (cam.zoom - cam.target_zoom).abs() <= RESET_TOLERANCE
+            && (cam.angle - cam.target_angle).abs() <= RESET_TOLERANCE;
+        if cancelled || arrived {
+            cam.resetting = false;
+        }
+        if arrived && !cancelled {
+            reset_complete.send(RtsCameraResetComplete { camera: entity });
+        }
+    }
+}
+
+fn send_change_events(
+    cam_q: Query<(Entity, Ref<RtsCamera>)>,
+    mut removed: RemovedComponents<RtsCamera>,
+    mut previous: Local<EntityHashMap<(Vec3, Quat, f32)>>,
+    mut moved: EventWriter<RtsCameraMoved>,
+    mut zoom_changed: EventWriter<RtsCameraZoomChanged>,
+    mut rotated: EventWriter<RtsCameraRotated>,
+    mut heading_changed: EventWriter<CameraHeadingChanged>,
+) {
+    for entity in removed.read() {
+        previous.remove(&entity);
+    }
+    for (entity, cam) in cam_q.iter() {
+        let current = (cam.focus.translation, cam.focus.rotation, cam.zoom);
+        // Cameras that haven't been touched can't have moved
+        if !cam.is_changed() && previous.contains_key(&entity) {
+            continue;
+        }
+        let Some((translation, rotation, zoom)) = previous.insert(entity, current) else {
+            continue;
+        };
+        if translation != current.0 {
+            moved.send(RtsCameraMoved {
+                camera: entity,
+                translation: current.0,
+            });
+        }
+        if rotation != current.1 {
+            rotated.send(RtsCameraRotated {
+                camera: entity,
+                rotation: current.1,
+            });
+            let heading = heading_degrees(cam.yaw_of(current.1));
+            if heading != heading_degrees(cam.yaw_of(rotation)) {
+                heading_changed.send(CameraHeadingChanged {
+                    camera: entity,
+                    heading,
+                });
+            }
+        }
+        if zoom != current.2 {
+            zoom_changed.send(RtsCameraZoomChanged {
+                camera: entity,
+                zoom: current.2,
+            });
+        }
+    }
 }
 
 fn ease_in_circular(x: f32) -> f32 {
@@ -0,0 +1,66 @@
+use crate::RtsCamera;
+use bevy::prelude::*;
+
+/// Component for a child entity of an `RtsCamera`, that keeps it where the player should hear the
+/// game from. RTS audio is usually centered on what the player is looking at, rather than the
+/// camera high above it, so by default it stays at the focus on the ground. Add a
+/// `SpatialListener` (or the listener of whichever audio crate you use) to the same entity.
+/// The listener keeps the camera's rotation, so left and right match what's on screen.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, RtsCameraListener};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(RtsCamera::default()).with_child((
+///         RtsCameraListener::default(),
+///         // SpatialListener::new(4.0),
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+#[require(Transform)]
+pub struct RtsCameraListener {
+    /// Where the listener is placed.
+    /// Defaults to `ListenerPlacement::Focus`.
+    pub placement: ListenerPlacement,
+}
+
+impl Default for RtsCameraListener {
+    fn default() -> Self {
+        RtsCameraListener {
+            placement: ListenerPlacement::Focus,
+        }
+    }
+}
+
+/// Where an `RtsCameraListener` is placed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ListenerPlacement {
+    /// At the point on the ground the camera is looking at (see `RtsCamera::ground_focus`).
+    Focus,
+    /// At the camera itself.
+    Camera,
+    /// Part of the way from the focus (`0.0`) to the camera (`1.0`), e.g. to hear a bit more of
+    /// the surrounding area when zoomed out.
+    Between(f32),
+}
+
+pub(crate) fn place_listeners(
+    cam_q: Query<(&Transform, &RtsCamera), Without<RtsCameraListener>>,
+    mut listener_q: Query<(&mut Transform, &RtsCameraListener, &Parent)>,
+) {
+    for (mut tfm, listener, parent) in listener_q.iter_mut() {
+        let Ok((cam_tfm, cam)) = cam_q.get(parent.get()) else {
+            continue;
+        };
+        let position = match listener.placement {
+            ListenerPlacement::Focus => cam.ground_focus(),
+            ListenerPlacement::Camera => cam_tfm.translation,
+            ListenerPlacement::Between(t) => cam.ground_focus().lerp(cam_tfm.translation, t),
+        };
+        // Relative to the camera, as it's a child of the camera
+        let translation =
+            cam_tfm.rotation.inverse() * (position - cam_tfm.translation) / cam_tfm.scale;
+        tfm.set_if_neq(Transform::from_translation(translation));
+    }
+}
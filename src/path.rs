@@ -0,0 +1,240 @@
+use crate::{CameraTime, RtsCamera};
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Component that plays a scripted camera move through a sequence of keyframes on the
+/// `RtsCamera` on the same entity, e.g. for cutscenes or benchmark flythroughs. The focus follows a
+/// smooth curve through the keyframes, while the zoom, yaw and angle are interpolated between
+/// them. `RtsCameraControls` is ignored while the path plays, and bounds aren't applied. The height
+/// of the focus still follows the ground.
+/// `CameraPathKeyframeReached` is sent as each keyframe is passed, and `CameraPathFinished` is sent
+/// (and this component removed) once the last one is reached, unless `looping` is enabled. Remove
+/// the component to stop the path early.
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{CameraPath, CameraPathKeyframe, RtsCamera};
+/// fn start_cutscene(mut commands: Commands, cam_q: Query<Entity, With<RtsCamera>>) {
+///     for cam in cam_q.iter() {
+///         commands.entity(cam).insert(CameraPath::new(vec![
+///             CameraPathKeyframe::new(Duration::ZERO, Vec3::ZERO),
+///             CameraPathKeyframe::new(Duration::from_secs(3), Vec3::new(10.0, 0.0, -10.0))
+///                 .with_zoom(0.8)
+///                 .with_yaw(1.0),
+///             CameraPathKeyframe::new(Duration::from_secs(5), Vec3::new(20.0, 0.0, -5.0))
+///                 .with_easing(EaseFunction::CubicOut),
+///         ]));
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct CameraPath {
+    /// The keyframes to move through, in order of `CameraPathKeyframe.time`.
+    pub keyframes: Vec<CameraPathKeyframe>,
+    /// Whether to start again from the beginning after reaching the last keyframe, instead of
+    /// finishing.
+    /// Defaults to `false`.
+    pub looping: bool,
+    /// How far through the path the camera is.
+    /// Updated automatically.
+    /// Defaults to `Duration::ZERO`.
+    pub elapsed: Duration,
+}
+
+impl CameraPath {
+    /// Creates a path through `keyframes` that plays once from the start.
+    pub fn new(keyframes: Vec<CameraPathKeyframe>) -> Self {
+        Self {
+            keyframes,
+            looping: false,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Returns how long the path takes to play, which is the time of the last keyframe.
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .last()
+            .map_or(Duration::ZERO, |keyframe| keyframe.time)
+    }
+}
+
+/// A point along a `CameraPath`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraPathKeyframe {
+    /// When the camera reaches this keyframe, relative to the start of the path.
+    pub time: Duration,
+    /// Where the focus is at this keyframe. The height is replaced by the height of the ground.
+    pub focus: Vec3,
+    /// The rotation of the focus in radians, around the normal of `RtsCamera.ground_plane`, where
+    /// `0.0` faces forward (-Z) and positive values rotate counterclockwise when looking down.
+    /// Yaw isn't wrapped, so going from `0.0` to `TAU` makes a full turn.
+    /// Defaults to `0.0`.
+    pub yaw: f32,
+    /// The zoom level at this keyframe (see `RtsCamera.zoom`).
+    /// Defaults to `0.0`.
+    pub zoom: f32,
+    /// The angle of the camera at this keyframe (see `RtsCamera.angle`).
+    /// Defaults to 20 degrees.
+    pub angle: f32,
+    /// The easing applied to the movement from the previous keyframe to this one.
+    /// Defaults to `EaseFunction::Linear`.
+    pub easing: EaseFunction,
+}
+
+impl CameraPathKeyframe {
+    /// Creates a keyframe at `time` with the focus at `focus`, and everything else at its default.
+    pub fn new(time: Duration, focus: Vec3) -> Self {
+        Self {
+            time,
+            focus,
+            yaw: 0.0,
+            zoom: 0.0,
+            angle: 20.0f32.to_radians(),
+            easing: EaseFunction::Linear,
+        }
+    }
+
+    /// Sets the yaw of the keyframe.
+    pub fn with_yaw(mut self, yaw: f32) -> Self {
+        self.yaw = yaw;
+        self
+    }
+
+    /// Sets the zoom of the keyframe.
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the angle of the keyframe.
+    pub fn with_angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Sets the easing of the movement into the keyframe.
+    pub fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Event sent when a camera playing a `CameraPath` passes one of its keyframes.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CameraPathKeyframeReached {
+    /// The camera playing the path.
+    pub camera: Entity,
+    /// The index of the keyframe in `CameraPath.keyframes`.
+    pub keyframe: usize,
+}
+
+/// Event sent when a camera finishes playing a `CameraPath` that isn't looping.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CameraPathFinished {
+    /// The camera that finished the path.
+    pub camera: Entity,
+}
+
+pub(crate) fn play_camera_paths(
+    mut cam_q: Query<(Entity, &mut RtsCamera, &mut CameraPath)>,
+    time: CameraTime,
+    mut keyframe_reached: EventWriter<CameraPathKeyframeReached>,
+    mut path_finished: EventWriter<CameraPathFinished>,
+    mut commands: Commands,
+) {
+    for (entity, mut cam, mut path) in cam_q.iter_mut() {
+        let duration = path.duration();
+        let previous = path.elapsed;
+        let mut elapsed = previous + time.delta();
+        let mut reached = path
+            .keyframes
+            .iter()
+            .enumerate()
+            .filter(|(_, keyframe)| {
+                (keyframe.time > previous || previous.is_zero()) && keyframe.time <= elapsed
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let finished = elapsed >= duration;
+        if finished && path.looping && !duration.is_zero() {
+            elapsed = Duration::from_secs_f64(elapsed.as_secs_f64() % duration.as_secs_f64());
+            reached.extend(
+                path.keyframes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, keyframe)| keyframe.time <= elapsed)
+                    .map(|(i, _)| i),
+            );
+        }
+        path.elapsed = elapsed;
+
+        if let Some((focus, yaw, zoom, angle)) = sample_path(&path.keyframes, elapsed) {
+            let focus_translation = cam.with_height_of(focus, cam.focus.translation);
+            let target_translation = cam.with_height_of(focus, cam.target_focus.translation);
+            let rotation = cam.plane_rotation() * Quat::from_rotation_y(yaw);
+            cam.target_focus.translation = target_translation;
+            cam.target_focus.rotation = rotation;
+            cam.focus.translation = focus_translation;
+            cam.focus.rotation = rotation;
+            cam.target_zoom = zoom.clamp(0.0, 1.0);
+            cam.zoom = cam.target_zoom;
+            cam.target_angle = angle;
+            cam.angle = angle;
+            cam.flight = None;
+        }
+
+        for keyframe in reached {
+            keyframe_reached.send(CameraPathKeyframeReached {
+                camera: entity,
+                keyframe,
+            });
+        }
+        if finished && !path.looping {
+            commands.entity(entity).remove::<CameraPath>();
+            path_finished.send(CameraPathFinished { camera: entity });
+        }
+    }
+}
+
+/// Returns the focus, yaw, zoom and angle along `keyframes` at `elapsed`, or `None` if there are
+/// no keyframes.
+fn sample_path(
+    keyframes: &[CameraPathKeyframe],
+    elapsed: Duration,
+) -> Option<(Vec3, f32, f32, f32)> {
+    let first = keyframes.first()?;
+    // The keyframe being moved towards
+    let Some(next) = keyframes
+        .iter()
+        .position(|keyframe| keyframe.time > elapsed)
+    else {
+        let last = keyframes.last()?;
+        return Some((last.focus, last.yaw, last.zoom, last.angle));
+    };
+    if next == 0 {
+        return Some((first.focus, first.yaw, first.zoom, first.angle));
+    }
+    let (from, to) = (&keyframes[next - 1], &keyframes[next]);
+    let t = (elapsed - from.time).as_secs_f32() / (to.time - from.time).as_secs_f32();
+    let t = EasingCurve::new(0.0, 1.0, to.easing).sample_clamped(t);
+
+    // Catmull-Rom, so the focus moves through each keyframe without sudden changes in direction
+    let before = keyframes.get(next.wrapping_sub(2)).unwrap_or(from).focus;
+    let after = keyframes.get(next + 1).unwrap_or(to).focus;
+    let (p0, p1, p2, p3) = (before, from.focus, to.focus, after);
+    let focus = 0.5
+        * ((2.0 * p1)
+            + (p2 - p0) * t
+            + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+            + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t * t * t);
+
+    Some((
+        focus,
+        from.yaw.lerp(to.yaw, t),
+        from.zoom.lerp(to.zoom, t),
+        from.angle.lerp(to.angle, t),
+    ))
+}
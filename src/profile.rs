@@ -0,0 +1,185 @@
+use crate::{RtsCamera, RtsCameraSystemSet};
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use std::time::Duration;
+
+pub struct RtsCameraProfilePlugin;
+
+impl Plugin for RtsCameraProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RtsCameraProfiles>()
+            .add_systems(Update, blend_profiles.before(RtsCameraSystemSet));
+    }
+}
+
+/// A named set of camera settings that can be switched to at runtime with
+/// `RtsCameraProfiles::switch_profile`. All values have the same meaning as the `RtsCamera` field
+/// of the same name.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RtsCameraProfile {
+    /// See `RtsCamera.height_min`.
+    pub height_min: f32,
+    /// See `RtsCamera.height_max`.
+    pub height_max: f32,
+    /// See `RtsCamera.min_angle`.
+    pub min_angle: f32,
+    /// See `RtsCamera.pan_smoothness`.
+    pub pan_smoothness: f32,
+    /// See `RtsCamera.zoom_smoothness`.
+    pub zoom_smoothness: f32,
+    /// See `RtsCamera.rotate_smoothness`.
+    pub rotate_smoothness: f32,
+    /// See `RtsCamera.angle_smoothness`.
+    pub angle_smoothness: f32,
+}
+
+impl Default for RtsCameraProfile {
+    fn default() -> Self {
+        Self::from_camera(&RtsCamera::default())
+    }
+}
+
+impl RtsCameraProfile {
+    /// Creates a profile from the current settings of `cam`.
+    pub fn from_camera(cam: &RtsCamera) -> Self {
+        RtsCameraProfile {
+            height_min: cam.height_min,
+            height_max: cam.height_max,
+            min_angle: cam.min_angle,
+            pan_smoothness: cam.pan_smoothness,
+            zoom_smoothness: cam.zoom_smoothness,
+            rotate_smoothness: cam.rotate_smoothness,
+            angle_smoothness: cam.angle_smoothness,
+        }
+    }
+
+    /// Applies this profile to `cam`.
+    pub fn apply(&self, cam: &mut RtsCamera) {
+        cam.height_min = self.height_min;
+        cam.height_max = self.height_max;
+        cam.min_angle = self.min_angle;
+        cam.pan_smoothness = self.pan_smoothness;
+        cam.zoom_smoothness = self.zoom_smoothness;
+        cam.rotate_smoothness = self.rotate_smoothness;
+        cam.angle_smoothness = self.angle_smoothness;
+    }
+
+    /// Linearly interpolates between this profile and `other`, where `t` of `0.0` is this profile
+    /// and `1.0` is `other`.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        RtsCameraProfile {
+            height_min: self.height_min.lerp(other.height_min, t),
+            height_max: self.height_max.lerp(other.height_max, t),
+            min_angle: self.min_angle.lerp(other.min_angle, t),
+            pan_smoothness: self.pan_smoothness.lerp(other.pan_smoothness, t),
+            zoom_smoothness: self.zoom_smoothness.lerp(other.zoom_smoothness, t),
+            rotate_smoothness: self.rotate_smoothness.lerp(other.rotate_smoothness, t),
+            angle_smoothness: self.angle_smoothness.lerp(other.angle_smoothness, t),
+        }
+    }
+}
+
+/// Resource that stores named `RtsCameraProfile`s (e.g. "gameplay", "cinematic", "map_editor"),
+/// and blends all `RtsCamera`s between them when switching.
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCameraProfile, RtsCameraProfiles};
+/// fn setup(mut profiles: ResMut<RtsCameraProfiles>) {
+///     profiles.insert("gameplay", RtsCameraProfile::default());
+///     profiles.insert(
+///         "cinematic",
+///         RtsCameraProfile {
+///             height_min: 1.0,
+///             pan_smoothness: 0.6,
+///             ..default()
+///         },
+///     );
+/// }
+///
+/// fn start_cutscene(mut profiles: ResMut<RtsCameraProfiles>) {
+///     profiles.switch_profile("cinematic", Duration::from_secs(2));
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct RtsCameraProfiles {
+    profiles: HashMap<String, RtsCameraProfile>,
+    active: Option<String>,
+    transition: Option<ProfileTransition>,
+}
+
+#[derive(Debug)]
+struct ProfileTransition {
+    to: RtsCameraProfile,
+    duration: Duration,
+    elapsed: Duration,
+    from: EntityHashMap<RtsCameraProfile>,
+}
+
+impl RtsCameraProfiles {
+    /// Adds a profile, replacing any existing profile with the same name.
+    pub fn insert(&mut self, name: impl Into<String>, profile: RtsCameraProfile) {
+        self.profiles.insert(name.into(), profile);
+    }
+
+    /// Returns the profile with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&RtsCameraProfile> {
+        self.profiles.get(name)
+    }
+
+    /// Returns the name of the most recently switched to profile, if any. Note that this changes
+    /// as soon as `switch_profile` is called, not when blending finishes.
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Returns whether the cameras are currently blending between two profiles.
+    pub fn is_blending(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Switches to the profile with the given name, blending all `RtsCamera`s from their current
+    /// settings over `duration`. Use `Duration::ZERO` to switch immediately. Returns `false` (and
+    /// does nothing) if there is no profile with that name.
+    pub fn switch_profile(&mut self, name: &str, duration: Duration) -> bool {
+        let Some(profile) = self.profiles.get(name) else {
+            return false;
+        };
+        self.transition = Some(ProfileTransition {
+            to: *profile,
+            duration,
+            elapsed: Duration::ZERO,
+            from: EntityHashMap::default(),
+        });
+        self.active = Some(name.to_string());
+        true
+    }
+}
+
+fn blend_profiles(
+    mut profiles: ResMut<RtsCameraProfiles>,
+    mut cam_q: Query<(Entity, &mut RtsCamera)>,
+    time: Res<Time<Real>>,
+) {
+    let Some(transition) = profiles.transition.as_mut() else {
+        return;
+    };
+    transition.elapsed += time.delta();
+    let t = if transition.duration.is_zero() {
+        1.0
+    } else {
+        (transition.elapsed.as_secs_f32() / transition.duration.as_secs_f32()).min(1.0)
+    };
+    for (entity, mut cam) in cam_q.iter_mut() {
+        let from = *transition
+            .from
+            .entry(entity)
+            .or_insert_with(|| RtsCameraProfile::from_camera(&cam));
+        from.lerp(&transition.to, t).apply(&mut cam);
+    }
+    if t >= 1.0 {
+        profiles.transition = None;
+    }
+}
@@ -0,0 +1,74 @@
+use crate::ground::GroundMesh;
+use crate::{GroundBackend, RtsCameraRayFilter};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{DefaultRapierContext, QueryFilter, RapierContext};
+
+/// `GroundBackend` that ray casts against bevy_rapier3d colliders instead of meshes. Only
+/// colliders on `Ground` entities (or below them in the hierarchy) are hit, following
+/// `GroundLayers`, `NotGround`, and `RtsCameraRayFilter` like the default backend. Uses the
+/// default `RapierContext`, and nothing is hit until it exists.
+/// Requires the `bevy_rapier3d` feature.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rapier3d::prelude::*;
+/// # use bevy_rts_camera::{Ground, RapierGround, RtsCameraPlugin};
+/// # fn main() {
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         RapierPhysicsPlugin::<NoUserData>::default(),
+///         RtsCameraPlugin::default().with_ground_backend::<RapierGround>(),
+///     ))
+///     .add_systems(Startup, setup)
+///     .run();
+/// # }
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Collider::cuboid(100.0, 0.5, 100.0),
+///         Transform::from_xyz(0.0, -0.5, 0.0),
+///         Ground,
+///     ));
+/// }
+/// ```
+pub struct RapierGround;
+
+/// The system param used by `RapierGround`.
+#[derive(SystemParam)]
+pub struct RapierGroundParam<'w, 's> {
+    context_q: Query<'w, 's, &'static RapierContext, With<DefaultRapierContext>>,
+    mesh_q: Query<'w, 's, &'static GroundMesh>,
+}
+
+impl GroundBackend for RapierGround {
+    type Param = RapierGroundParam<'static, 'static>;
+
+    fn cast_rays(
+        param: &RapierGroundParam,
+        rays: &[(Ray3d, Option<&RtsCameraRayFilter>)],
+    ) -> Vec<Option<Vec3>> {
+        let Ok(context) = param.context_q.get_single() else {
+            return vec![None; rays.len()];
+        };
+        rays.iter()
+            .map(|(ray, filter)| {
+                let predicate = |entity| {
+                    param
+                        .mesh_q
+                        .get(entity)
+                        .is_ok_and(|mesh| mesh.accepts(entity, *filter))
+                };
+                context
+                    .cast_ray(
+                        ray.origin,
+                        *ray.direction,
+                        f32::MAX,
+                        true,
+                        QueryFilter::default().predicate(&predicate),
+                    )
+                    .map(|(_, distance)| ray.get_point(distance))
+            })
+            .collect()
+    }
+}
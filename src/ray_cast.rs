@@ -0,0 +1,182 @@
+use bevy::ecs::entity::EntityHashSet;
+use bevy::ecs::query::QueryFilter;
+use bevy::ecs::system::SystemParam;
+use bevy::math::bounding::Aabb3d;
+use bevy::math::Vec3A;
+use bevy::picking::mesh_picking::ray_cast::{
+    ray_mesh_intersection, Backfaces, RayCastBackfaces, RayMeshHit, SimplifiedMesh,
+};
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::primitives::Aabb;
+
+/// Casts many rays at once against the meshes matching `F`. Unlike `MeshRayCast`, which checks
+/// every mesh in the world for every ray, this only looks at the pre-filtered set of meshes, and
+/// does a single pass over them for all rays, so the mesh lookup and transform are shared between
+/// rays that hit the same mesh. Hidden meshes are ignored (unless using
+/// `cast_rays_including_hidden`), but meshes that are out of view are not. Nothing is hit if
+/// there are no mesh assets, e.g. when running headless without `AssetPlugin`.
+#[derive(SystemParam)]
+pub(crate) struct BatchRayCast<'w, 's, F: QueryFilter + 'static> {
+    meshes: Option<Res<'w, Assets<Mesh>>>,
+    targets: Query<'w, 's, RayCastTarget, F>,
+}
+
+type RayCastTarget = (
+    Entity,
+    &'static Mesh3d,
+    Option<&'static SimplifiedMesh>,
+    Has<RayCastBackfaces>,
+    &'static GlobalTransform,
+    &'static Aabb,
+    &'static InheritedVisibility,
+);
+
+impl<F: QueryFilter + 'static> BatchRayCast<'_, '_, F> {
+    /// Returns whether there are any meshes that rays could hit.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Casts all `rays`, returning the nearest hit for each (in the same order as `rays`).
+    /// `filter` is called with the index of the ray and an entity the ray might hit, and should
+    /// return `false` if the ray should ignore that entity.
+    pub(crate) fn cast_rays(
+        &self,
+        rays: &[Ray3d],
+        filter: impl Fn(usize, Entity) -> bool,
+    ) -> Vec<Option<RayMeshHit>> {
+        self.cast_rays_among(rays, None, filter)
+    }
+
+    /// Same as `cast_rays`, but only tests the meshes in `candidates`, if given.
+    pub(crate) fn cast_rays_among(
+        &self,
+        rays: &[Ray3d],
+        candidates: Option<&EntityHashSet>,
+        filter: impl Fn(usize, Entity) -> bool,
+    ) -> Vec<Option<RayMeshHit>> {
+        self.cast_rays_inner(rays, candidates, false, filter)
+            .into_iter()
+            .map(|hit| hit.map(|(_, hit)| hit))
+            .collect()
+    }
+
+    /// Same as `cast_rays`, but hidden meshes can be hit too, and the entity that was hit is
+    /// returned along with each hit.
+    pub(crate) fn cast_rays_including_hidden(
+        &self,
+        rays: &[Ray3d],
+        filter: impl Fn(usize, Entity) -> bool,
+    ) -> Vec<Option<(Entity, RayMeshHit)>> {
+        self.cast_rays_inner(rays, None, true, filter)
+    }
+
+    fn cast_rays_inner(
+        &self,
+        rays: &[Ray3d],
+        candidates: Option<&EntityHashSet>,
+        include_hidden: bool,
+        filter: impl Fn(usize, Entity) -> bool,
+    ) -> Vec<Option<(Entity, RayMeshHit)>> {
+        let mut hits: Vec<Option<(Entity, RayMeshHit)>> = vec![None; rays.len()];
+        let Some(meshes) = self.meshes.as_ref().filter(|_| !rays.is_empty()) else {
+            return hits;
+        };
+        let targets: Box<dyn Iterator<Item = _>> = match candidates {
+            Some(candidates) => Box::new(self.targets.iter_many(candidates)),
+            None => Box::new(self.targets.iter()),
+        };
+        for (entity, mesh3d, simplified_mesh, has_backfaces, transform, aabb, visibility) in targets
+        {
+            if !visibility.get() && !include_hidden {
+                continue;
+            }
+            let transform = transform.compute_matrix();
+            // Shared by every ray, rather than inverted again for each one
+            let world_to_model = transform.inverse();
+            let aabb = Aabb3d::new(aabb.center, aabb.half_extents);
+            let handle = simplified_mesh.map_or(&mesh3d.0, |m| &m.0);
+            let backfaces = if has_backfaces {
+                Backfaces::Include
+            } else {
+                Backfaces::Cull
+            };
+            // Only resolved once the first ray hits the AABB, then reused for the rest
+            let mut mesh = None;
+            for (i, ray) in rays.iter().enumerate() {
+                let Some(aabb_near) = ray_aabb_intersection(*ray, &aabb, &world_to_model) else {
+                    continue;
+                };
+                // Can't possibly be closer than what we've already hit
+                if hits[i]
+                    .as_ref()
+                    .is_some_and(|(_, hit)| hit.distance < aabb_near)
+                {
+                    continue;
+                }
+                if !filter(i, entity) {
+                    continue;
+                }
+                if mesh.is_none() {
+                    mesh = meshes.get(handle);
+                }
+                let Some(mesh) = mesh else {
+                    break;
+                };
+                if let Some(hit) = ray_intersection_over_mesh(mesh, &transform, *ray, backfaces) {
+                    if hits[i]
+                        .as_ref()
+                        .is_none_or(|(_, nearest)| hit.distance < nearest.distance)
+                    {
+                        hits[i] = Some((entity, hit));
+                    }
+                }
+            }
+        }
+        hits
+    }
+}
+
+/// Returns the distance along `ray` to where it enters `aabb` (in model space), if it hits it.
+/// Same as `ray_aabb_intersection_3d` from `bevy_picking`, but takes the inverse of the model's
+/// transform, so it can be reused for many rays.
+fn ray_aabb_intersection(ray: Ray3d, aabb: &Aabb3d, world_to_model: &Mat4) -> Option<f32> {
+    let ray_direction = world_to_model.transform_vector3a((*ray.direction).into());
+    let ray_direction_recip = ray_direction.recip();
+    let ray_origin = world_to_model.transform_point3a(ray.origin.into());
+    let positive = ray_direction.signum().cmpgt(Vec3A::ZERO);
+    let min = Vec3A::select(positive, aabb.min, aabb.max);
+    let max = Vec3A::select(positive, aabb.max, aabb.min);
+    // Axes the ray doesn't move along give NaN, which min/max ignore
+    let tmin = ((min - ray_origin) * ray_direction_recip)
+        .max_element()
+        .max(0.0);
+    let tmax = ((max - ray_origin) * ray_direction_recip).min_element();
+    (tmin <= tmax).then_some(tmin)
+}
+
+// Same as the private function of the same name in `bevy_picking`.
+fn ray_intersection_over_mesh(
+    mesh: &Mesh,
+    transform: &Mat4,
+    ray: Ray3d,
+    culling: Backfaces,
+) -> Option<RayMeshHit> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+    let normals = mesh
+        .attribute(Mesh::ATTRIBUTE_NORMAL)
+        .and_then(|normal_values| normal_values.as_float3());
+    match mesh.indices() {
+        Some(Indices::U16(indices)) => {
+            ray_mesh_intersection(ray, transform, positions, normals, Some(indices), culling)
+        }
+        Some(Indices::U32(indices)) => {
+            ray_mesh_intersection(ray, transform, positions, normals, Some(indices), culling)
+        }
+        None => ray_mesh_intersection::<usize>(ray, transform, positions, normals, None, culling),
+    }
+}
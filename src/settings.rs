@@ -0,0 +1,241 @@
+use crate::{CameraBounds, RtsCamera};
+#[cfg(feature = "controller")]
+use crate::{EdgePanWidths, RtsCameraControls};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::math::bounding::Aabb2d;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub struct RtsCameraSettingsPlugin;
+
+impl Plugin for RtsCameraSettingsPlugin {
+    fn build(&self, _app: &mut App) {}
+
+    // Done once all plugins are added, so it doesn't matter whether `AssetPlugin` comes first
+    fn finish(&self, app: &mut App) {
+        // Without `AssetPlugin` (e.g. when running headless) there's nothing to load settings with
+        if !app.world().contains_resource::<AssetServer>() {
+            return;
+        }
+        app.init_asset::<RtsCameraSettings>()
+            .init_asset_loader::<RtsCameraSettingsLoader>()
+            .add_systems(PreUpdate, apply_settings);
+    }
+}
+
+/// Camera settings that can be loaded from a RON file (with the `.rts_camera.ron` extension) via
+/// the asset server. Attach the loaded handle to a camera with `RtsCameraSettingsHandle` and the
+/// settings will be applied to its `RtsCamera` and `RtsCameraControls` whenever the asset is
+/// loaded or changes. Combined with Bevy's `file_watcher` feature, this allows tuning the camera
+/// without recompiling.
+/// Every field is optional, and fields that are left out won't be changed.
+/// Requires the `settings_asset` feature.
+/// # Example
+/// ```ron
+/// (
+///     height_max: Some(50.0),
+///     min_angle: Some(0.6),
+///     smoothness: Some(0.1),
+///     keys_up: Some([KeyW, ArrowUp]),
+///     pan_speed: Some(25.0),
+/// )
+/// ```
+#[derive(Asset, TypePath, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RtsCameraSettings {
+    /// See `RtsCamera.height_min`.
+    pub height_min: Option<f32>,
+    /// See `RtsCamera.height_max`.
+    pub height_max: Option<f32>,
+    /// The minimum corner of `RtsCamera.bounds`. Only applied if `bounds_max` is also set.
+    pub bounds_min: Option<Vec2>,
+    /// The maximum corner of `RtsCamera.bounds`. Only applied if `bounds_min` is also set.
+    pub bounds_max: Option<Vec2>,
+    /// See `RtsCamera.min_angle`.
+    pub min_angle: Option<f32>,
+    /// See `RtsCamera.dynamic_angle`.
+    pub dynamic_angle: Option<bool>,
+    /// Sets all of `RtsCamera`'s smoothness fields at once. See `RtsCamera::set_smoothness`.
+    /// Applied before the individual smoothness settings below.
+    pub smoothness: Option<f32>,
+    /// See `RtsCamera.pan_smoothness`.
+    pub pan_smoothness: Option<f32>,
+    /// See `RtsCamera.zoom_smoothness`.
+    pub zoom_smoothness: Option<f32>,
+    /// See `RtsCamera.rotate_smoothness`.
+    pub rotate_smoothness: Option<f32>,
+    /// See `RtsCamera.angle_smoothness`.
+    pub angle_smoothness: Option<f32>,
+    /// See `RtsCameraControls.keys_up`.
+    pub keys_up: Option<Vec<KeyCode>>,
+    /// See `RtsCameraControls.keys_down`.
+    pub keys_down: Option<Vec<KeyCode>>,
+    /// See `RtsCameraControls.keys_left`.
+    pub keys_left: Option<Vec<KeyCode>>,
+    /// See `RtsCameraControls.keys_right`.
+    pub keys_right: Option<Vec<KeyCode>>,
+    /// See `RtsCameraControls.button_rotate`.
+    pub button_rotate: Option<MouseButton>,
+    /// See `RtsCameraControls.keys_rotate_left`.
+    pub keys_rotate_left: Option<Vec<KeyCode>>,
+    /// See `RtsCameraControls.keys_rotate_right`.
+    pub keys_rotate_right: Option<Vec<KeyCode>>,
+    /// See `RtsCameraControls.key_rotate_speed`.
+    pub key_rotate_speed: Option<f32>,
+    /// See `RtsCameraControls.button_drag`. Note that `Some(None)` disables drag panning.
+    pub button_drag: Option<Option<MouseButton>>,
+    /// See `RtsCameraControls.edge_pan_width`. Sets the same width for every edge.
+    pub edge_pan_width: Option<f32>,
+    /// See `RtsCameraControls.pan_speed`.
+    pub pan_speed: Option<f32>,
+    /// See `RtsCameraControls.zoom_sensitivity`.
+    pub zoom_sensitivity: Option<f32>,
+}
+
+impl RtsCameraSettings {
+    /// Applies any settings that are set to `cam`.
+    pub fn apply_to_camera(&self, cam: &mut RtsCamera) {
+        set(&mut cam.height_min, self.height_min);
+        set(&mut cam.height_max, self.height_max);
+        if let (Some(min), Some(max)) = (self.bounds_min, self.bounds_max) {
+            cam.bounds = CameraBounds::Aabb(Aabb2d { min, max });
+        }
+        set(&mut cam.min_angle, self.min_angle);
+        set(&mut cam.dynamic_angle, self.dynamic_angle);
+        if let Some(smoothness) = self.smoothness {
+            cam.set_smoothness(smoothness);
+        }
+        set(&mut cam.pan_smoothness, self.pan_smoothness);
+        set(&mut cam.zoom_smoothness, self.zoom_smoothness);
+        set(&mut cam.rotate_smoothness, self.rotate_smoothness);
+        set(&mut cam.angle_smoothness, self.angle_smoothness);
+    }
+
+    /// Applies any settings that are set to `controls`.
+    /// Requires the `controller` feature.
+    #[cfg(feature = "controller")]
+    pub fn apply_to_controls(&self, controls: &mut RtsCameraControls) {
+        set(&mut controls.keys_up, self.keys_up.clone());
+        set(&mut controls.keys_down, self.keys_down.clone());
+        set(&mut controls.keys_left, self.keys_left.clone());
+        set(&mut controls.keys_right, self.keys_right.clone());
+        set(&mut controls.button_rotate, self.button_rotate);
+        set(
+            &mut controls.keys_rotate_left,
+            self.keys_rotate_left.clone(),
+        );
+        set(
+            &mut controls.keys_rotate_right,
+            self.keys_rotate_right.clone(),
+        );
+        set(&mut controls.key_rotate_speed, self.key_rotate_speed);
+        set(&mut controls.button_drag, self.button_drag);
+        set(
+            &mut controls.edge_pan_width,
+            self.edge_pan_width.map(EdgePanWidths::all),
+        );
+        set(&mut controls.pan_speed, self.pan_speed);
+        set(&mut controls.zoom_sensitivity, self.zoom_sensitivity);
+    }
+}
+
+fn set<T>(field: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *field = value;
+    }
+}
+
+/// Links an `RtsCamera` to an `RtsCameraSettings` asset, which will be applied to it whenever the
+/// asset is loaded or modified.
+/// Requires the `settings_asset` feature.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, RtsCameraSettingsHandle};
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         RtsCamera::default(),
+///         RtsCameraSettingsHandle(asset_server.load("camera.rts_camera.ron")),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct RtsCameraSettingsHandle(pub Handle<RtsCameraSettings>);
+
+/// Errors that can occur when loading an `RtsCameraSettings` asset.
+#[derive(Debug)]
+pub enum RtsCameraSettingsLoaderError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not valid RON, or doesn't match the format of `RtsCameraSettings`.
+    Ron(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for RtsCameraSettingsLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read camera settings: {err}"),
+            Self::Ron(err) => write!(f, "could not parse camera settings: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RtsCameraSettingsLoaderError {}
+
+#[derive(Default)]
+struct RtsCameraSettingsLoader;
+
+impl AssetLoader for RtsCameraSettingsLoader {
+    type Asset = RtsCameraSettings;
+    type Settings = ();
+    type Error = RtsCameraSettingsLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(RtsCameraSettingsLoaderError::Io)?;
+        ron::de::from_bytes(&bytes).map_err(RtsCameraSettingsLoaderError::Ron)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rts_camera.ron"]
+    }
+}
+
+// The entity is only needed to find the controls
+#[cfg_attr(not(feature = "controller"), allow(unused_variables))]
+fn apply_settings(
+    mut cam_q: Query<(Entity, Ref<RtsCameraSettingsHandle>, &mut RtsCamera)>,
+    #[cfg(feature = "controller")] mut controls_q: Query<&mut RtsCameraControls>,
+    mut asset_events: EventReader<AssetEvent<RtsCameraSettings>>,
+    settings: Res<Assets<RtsCameraSettings>>,
+) {
+    let changed = asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for (entity, handle, mut cam) in cam_q.iter_mut() {
+        if !handle.is_changed() && !changed.contains(&handle.0.id()) {
+            continue;
+        }
+        let Some(settings) = settings.get(&handle.0) else {
+            continue;
+        };
+        settings.apply_to_camera(&mut cam);
+        #[cfg(feature = "controller")]
+        if let Ok(mut controls) = controls_q.get_mut(entity) {
+            settings.apply_to_controls(&mut controls);
+        }
+    }
+}
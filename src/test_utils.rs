@@ -0,0 +1,196 @@
+//! Helpers for writing deterministic tests of camera behaviour, without a window or rendering.
+//!
+//! `RtsCameraTestApp` runs `RtsCameraPlugin` on `MinimalPlugins` with a fixed frame time, so every
+//! run gives the same result. Ground can be added with `set_ground` (using `HeightmapGround`, as
+//! there are no meshes), and keyboard input or `RtsCameraInput` events can be injected. The mouse
+//! controls need a window with a cursor, so drive those with `RtsCameraInput` instead.
+//!
+//! Only available with the `test_utils` feature, e.g. as a dev-dependency of your game.
+//!
+//! # Example
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_rts_camera::test_utils::RtsCameraTestApp;
+//! # use bevy_rts_camera::RtsCamera;
+//! # #[cfg(feature = "controller")]
+//! # use bevy_rts_camera::RtsCameraControls;
+//! let mut app = RtsCameraTestApp::new();
+//! # #[cfg(feature = "controller")]
+//! let cam = app.spawn_camera((RtsCamera::default(), RtsCameraControls::default()));
+//! # #[cfg(not(feature = "controller"))]
+//! # let cam = app.spawn_camera(RtsCamera::default());
+//! app.step(1);
+//!
+//! // Smoothing: the camera eases towards its target
+//! app.camera_mut(cam).target_focus.translation = Vec3::new(10.0, 0.0, 0.0);
+//! app.step(1);
+//! let x = app.camera(cam).focus.translation.x;
+//! assert!(x > 0.0 && x < 10.0);
+//! app.step(120);
+//! assert_eq!(app.camera(cam).focus.translation, Vec3::new(10.0, 0.0, 0.0));
+//!
+//! // Bounds: the target is kept within `RtsCamera.bounds`
+//! app.camera_mut(cam).target_focus.translation = Vec3::new(100.0, 0.0, 0.0);
+//! app.step(1);
+//! assert_eq!(app.camera(cam).target_focus.translation.x, 20.0);
+//!
+//! // Ground follow: the focus moves to the height of the ground
+//! app.set_ground(|_| Some(5.0));
+//! app.step(120);
+//! assert_eq!(app.camera(cam).focus.translation.y, 5.0);
+//!
+//! // Input: holding the up arrow pans forward (with the `controller` feature)
+//! # #[cfg(feature = "controller")]
+//! # {
+//! let z = app.camera(cam).target_focus.translation.z;
+//! app.press_key(KeyCode::ArrowUp);
+//! app.step(10);
+//! assert!(app.camera(cam).target_focus.translation.z < z);
+//! # }
+//! ```
+
+use crate::ground::GroundIndex;
+use crate::{HeightmapGround, RtsCamera, RtsCameraInput, RtsCameraPlugin};
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::input::{ButtonState, InputPlugin};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+/// The frame time of an `RtsCameraTestApp`, unless changed with `with_frame_time`.
+const FRAME_TIME: Duration = Duration::from_nanos(16_666_667);
+
+/// A minimal app for testing cameras, with no window or rendering, and time that advances by a
+/// fixed amount every frame. Derefs to the underlying `App` for anything not covered here. See
+/// the module documentation for an example.
+pub struct RtsCameraTestApp {
+    app: App,
+    frame_time: Duration,
+}
+
+impl RtsCameraTestApp {
+    /// Creates an app with `RtsCameraPlugin::default()`.
+    pub fn new() -> Self {
+        Self::with_plugin(RtsCameraPlugin::default())
+    }
+
+    /// Creates an app with a customised `RtsCameraPlugin`, e.g. one with focus constraints.
+    pub fn with_plugin(plugin: RtsCameraPlugin) -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, plugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME_TIME));
+        Self {
+            app,
+            frame_time: FRAME_TIME,
+        }
+    }
+
+    /// Sets how much time passes each frame.
+    /// Defaults to 1/60th of a second.
+    pub fn with_frame_time(mut self, frame_time: Duration) -> Self {
+        self.frame_time = frame_time;
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+        self
+    }
+
+    /// Spawns a camera (e.g. an `RtsCamera` with `RtsCameraControls`), returning its entity.
+    pub fn spawn_camera(&mut self, bundle: impl Bundle) -> Entity {
+        self.app.world_mut().spawn(bundle).id()
+    }
+
+    /// Replaces the ground with a height function (see `HeightmapGround::new`).
+    pub fn set_ground(&mut self, height: impl Fn(Vec2) -> Option<f32> + Send + Sync + 'static) {
+        self.app.insert_resource(HeightmapGround::new(height));
+    }
+
+    /// Removes the grid that ground ray casts use to skip meshes far from the ray, so every ray
+    /// tests every `Ground` mesh, e.g. to benchmark the difference it makes.
+    pub fn remove_ground_index(&mut self) {
+        self.app.world_mut().remove_resource::<GroundIndex>();
+    }
+
+    /// Runs `frames` frames.
+    pub fn step(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.app.update();
+        }
+    }
+
+    /// Runs as many frames as it takes for at least `duration` to pass.
+    pub fn run_for(&mut self, duration: Duration) {
+        let frames = duration.as_secs_f64() / self.frame_time.as_secs_f64();
+        self.step(frames.ceil() as usize);
+    }
+
+    /// Returns the `RtsCamera` of `camera`. Panics if it doesn't have one.
+    pub fn camera(&self, camera: Entity) -> &RtsCamera {
+        self.app
+            .world()
+            .get::<RtsCamera>(camera)
+            .expect("entity should have an RtsCamera")
+    }
+
+    /// Returns the `RtsCamera` of `camera` for changing it. Panics if it doesn't have one.
+    pub fn camera_mut(&mut self, camera: Entity) -> Mut<'_, RtsCamera> {
+        self.app
+            .world_mut()
+            .get_mut::<RtsCamera>(camera)
+            .expect("entity should have an RtsCamera")
+    }
+
+    /// Returns the `Transform` of `camera`. Panics if it doesn't have one.
+    pub fn camera_transform(&self, camera: Entity) -> Transform {
+        *self
+            .app
+            .world()
+            .get::<Transform>(camera)
+            .expect("entity should have a Transform")
+    }
+
+    /// Presses `key`, which stays held until `release_key`.
+    pub fn press_key(&mut self, key: KeyCode) {
+        self.send_key(key, ButtonState::Pressed);
+    }
+
+    /// Releases `key`.
+    pub fn release_key(&mut self, key: KeyCode) {
+        self.send_key(key, ButtonState::Released);
+    }
+
+    /// Sends an `RtsCameraInput`, which is applied on the next frame.
+    pub fn send_input(&mut self, input: RtsCameraInput) {
+        self.app.world_mut().send_event(input);
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, state: ButtonState) {
+        self.app.world_mut().send_event(KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(NativeKey::Unidentified),
+            state,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+}
+
+impl Default for RtsCameraTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for RtsCameraTestApp {
+    type Target = App;
+
+    fn deref(&self) -> &App {
+        &self.app
+    }
+}
+
+impl DerefMut for RtsCameraTestApp {
+    fn deref_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{CameraTime, RtsCamera};
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Component that blends the `RtsCamera` on the same entity in from another view over `duration`,
+/// e.g. when leaving a first-person hero camera or an orbiting menu camera. The camera's
+/// `Transform` starts at the other view and eases towards where the `RtsCamera` would put it, so
+/// the controls (and anything else moving the camera) gradually take over as the transition
+/// plays. Switching the other camera off is left to you.
+/// `CameraTransitionFinished` is sent (and this component removed) once the transition is over.
+/// Remove the component to stop the transition early.
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{CameraTransition, RtsCamera};
+/// fn leave_hero_cam(
+///     mut commands: Commands,
+///     mut hero_cam_q: Query<(Entity, &mut Camera), Without<RtsCamera>>,
+///     rts_cam_q: Query<Entity, With<RtsCamera>>,
+/// ) {
+///     let (Ok((hero_cam, mut camera)), Ok(rts_cam)) =
+///         (hero_cam_q.get_single_mut(), rts_cam_q.get_single())
+///     else {
+///         return;
+///     };
+///     camera.is_active = false;
+///     commands.entity(rts_cam).insert(
+///         CameraTransition::from_camera(hero_cam, Duration::from_secs(2))
+///             .with_easing(EaseFunction::QuadraticInOut),
+///     );
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub struct CameraTransition {
+    /// The camera to blend from, which is followed if it moves during the transition, or `None` to
+    /// blend from wherever this camera was when the transition started.
+    pub from: Option<Entity>,
+    /// How long the transition takes.
+    pub duration: Duration,
+    /// How quickly the `RtsCamera` takes over during the transition.
+    /// Defaults to `EaseFunction::CubicInOut`.
+    pub easing: EaseFunction,
+    /// How far through the transition the camera is.
+    /// Updated automatically.
+    /// Defaults to `Duration::ZERO`.
+    pub elapsed: Duration,
+    /// Where this camera was when the transition started, which is blended from when `from` is
+    /// `None`.
+    /// Updated automatically.
+    /// Defaults to `None`.
+    pub start: Option<Transform>,
+}
+
+impl CameraTransition {
+    /// Creates a transition from wherever the camera currently is, e.g. when another controller
+    /// was moving the same camera entity.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            from: None,
+            duration,
+            easing: EaseFunction::CubicInOut,
+            elapsed: Duration::ZERO,
+            start: None,
+        }
+    }
+
+    /// Creates a transition from the view of the camera `from`.
+    pub fn from_camera(from: Entity, duration: Duration) -> Self {
+        Self {
+            from: Some(from),
+            ..Self::new(duration)
+        }
+    }
+
+    /// Sets the easing of the transition.
+    pub fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Event sent when a camera finishes a `CameraTransition`.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CameraTransitionFinished {
+    /// The camera that finished the transition.
+    pub camera: Entity,
+}
+
+pub(crate) fn blend_transitions(
+    mut cam_q: Query<(
+        Entity,
+        &mut Transform,
+        &GlobalTransform,
+        &RtsCamera,
+        &mut CameraTransition,
+    )>,
+    global_q: Query<&GlobalTransform>,
+    time: CameraTime,
+    mut transition_finished: EventWriter<CameraTransitionFinished>,
+    mut commands: Commands,
+) {
+    for (entity, mut tfm, global_tfm, cam, mut transition) in cam_q.iter_mut() {
+        // Transform propagation hasn't run yet, so this is where the camera was last frame
+        let start = *transition
+            .start
+            .get_or_insert_with(|| global_tfm.compute_transform());
+        transition.elapsed += time.delta();
+        if transition.elapsed >= transition.duration {
+            commands.entity(entity).remove::<CameraTransition>();
+            transition_finished.send(CameraTransitionFinished { camera: entity });
+            continue;
+        }
+        // The `Transform` isn't written while something else is in control, so don't blend it
+        if cam.external_transform_control {
+            continue;
+        }
+        let from = match transition.from {
+            Some(from) => global_q
+                .get(from)
+                .map_or(start, GlobalTransform::compute_transform),
+            None => start,
+        };
+        let t = EasingCurve::new(0.0, 1.0, transition.easing)
+            .sample_clamped(transition.elapsed.as_secs_f32() / transition.duration.as_secs_f32());
+        let blended = Transform {
+            translation: from.translation.lerp(tfm.translation, t),
+            rotation: from.rotation.slerp(tfm.rotation, t),
+            scale: tfm.scale,
+        };
+        tfm.set_if_neq(blended);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::RtsCamera;
+use bevy::math::bounding::Aabb3d;
+use bevy::prelude::*;
+use std::ops::RangeInclusive;
+
+/// Component that limits how far the camera can zoom (and optionally its angle) while its focus is
+/// inside a region of the map, such as an indoor area or a tactical zoom zone. Spawn it on its own
+/// entity. Outside the volume, the limits fade out over `blend_distance`, so the camera eases into
+/// them instead of jumping. Where volumes overlap, their limits are blended by how far inside each
+/// one the focus is.
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy::math::bounding::Aabb3d;
+/// # use bevy_rts_camera::ZoomConstraintVolume;
+/// fn setup(mut commands: Commands) {
+///     // Keep the camera close and low inside the cave
+///     commands.spawn(
+///         ZoomConstraintVolume::new(Aabb3d::new(Vec3::new(40.0, 0.0, 0.0), Vec3::splat(10.0)))
+///             .with_zoom(0.6..=1.0)
+///             .with_angle(0.9..=1.2),
+///     );
+/// }
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct ZoomConstraintVolume {
+    /// The region, in world space, that the focus must be in for the limits to apply fully.
+    pub bounds: Aabb3d,
+    /// The range that `RtsCamera.target_zoom` is kept within.
+    /// Defaults to `0.0..=1.0` (no limit).
+    pub zoom: RangeInclusive<f32>,
+    /// The range in radians that `RtsCamera.target_angle` is kept within, or `None` to leave the
+    /// angle alone.
+    /// Defaults to `None`.
+    pub angle: Option<RangeInclusive<f32>>,
+    /// How far outside `bounds` the limits start to take effect, growing stronger the closer the
+    /// focus gets. Set to `0.0` for the limits to apply as soon as the focus enters `bounds`.
+    /// Defaults to `4.0`.
+    pub blend_distance: f32,
+}
+
+impl ZoomConstraintVolume {
+    /// Creates a volume covering `bounds` that doesn't limit anything yet.
+    pub fn new(bounds: Aabb3d) -> Self {
+        Self {
+            bounds,
+            zoom: 0.0..=1.0,
+            angle: None,
+            blend_distance: 4.0,
+        }
+    }
+
+    /// Sets the range the zoom is kept within.
+    pub fn with_zoom(mut self, zoom: RangeInclusive<f32>) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the range the angle is kept within.
+    pub fn with_angle(mut self, angle: RangeInclusive<f32>) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+
+    /// Sets the distance over which the limits fade out.
+    pub fn with_blend_distance(mut self, blend_distance: f32) -> Self {
+        self.blend_distance = blend_distance;
+        self
+    }
+
+    /// Returns how strongly the limits apply to a focus at `point`, from `0.0` (not at all) to
+    /// `1.0` (fully, when inside `bounds`).
+    fn weight(&self, point: Vec3) -> f32 {
+        let distance = Vec3::from(self.bounds.closest_point(point)).distance(point);
+        if distance == 0.0 {
+            1.0
+        } else if self.blend_distance > 0.0 {
+            (1.0 - distance / self.blend_distance).max(0.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Accumulates the limits of the volumes around a focus, weighted by how strongly each applies.
+#[derive(Default)]
+struct BlendedRange {
+    min: f32,
+    max: f32,
+    total_weight: f32,
+    strongest: f32,
+}
+
+impl BlendedRange {
+    fn add(&mut self, range: &RangeInclusive<f32>, weight: f32) {
+        self.min += range.start() * weight;
+        self.max += range.end() * weight;
+        self.total_weight += weight;
+        self.strongest = self.strongest.max(weight);
+    }
+
+    /// Returns the blended range, faded towards `unconstrained` by how strong the strongest volume
+    /// is, or `None` if no volume applies.
+    fn resolve(&self, unconstrained: RangeInclusive<f32>) -> Option<RangeInclusive<f32>> {
+        if self.total_weight <= 0.0 {
+            return None;
+        }
+        let min = unconstrained
+            .start()
+            .lerp(self.min / self.total_weight, self.strongest);
+        let max = unconstrained
+            .end()
+            .lerp(self.max / self.total_weight, self.strongest);
+        Some(min..=max.max(min))
+    }
+}
+
+pub(crate) fn apply_zoom_constraints(
+    mut cam_q: Query<&mut RtsCamera>,
+    volume_q: Query<&ZoomConstraintVolume>,
+) {
+    if volume_q.is_empty() {
+        return;
+    }
+    for mut cam in cam_q.iter_mut() {
+        let focus = cam.target_focus.translation;
+        let mut zoom = BlendedRange::default();
+        let mut angle = BlendedRange::default();
+        for volume in volume_q.iter() {
+            let weight = volume.weight(focus);
+            if weight <= 0.0 {
+                continue;
+            }
+            zoom.add(&volume.zoom, weight);
+            if let Some(range) = &volume.angle {
+                angle.add(range, weight);
+            }
+        }
+
+        if let Some(range) = zoom.resolve(0.0..=1.0) {
+            let target_zoom = cam.target_zoom.clamp(*range.start(), *range.end());
+            if cam.target_zoom != target_zoom {
+                cam.target_zoom = target_zoom;
+            }
+        }
+        let max_angle = cam.max_angle.max(cam.min_angle);
+        if let Some(range) = angle.resolve(cam.min_angle..=max_angle) {
+            let target_angle = cam.target_angle.clamp(*range.start(), *range.end());
+            if cam.target_angle != target_angle {
+                cam.target_angle = target_angle;
+            }
+        }
+    }
+}
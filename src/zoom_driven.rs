@@ -0,0 +1,136 @@
+use crate::RtsCamera;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
+use bevy::prelude::*;
+use std::sync::Arc;
+
+/// Component that updates another component on the same entity whenever an `RtsCamera`'s zoom
+/// changes, e.g. to widen the field of view when zoomed out, push fog further away, or shorten
+/// shadow distances when zoomed in. `Projection` is supported out of the box. For any other
+/// component, register it with `RtsCameraPlugin::with_zoom_driven`.
+/// The camera is the `RtsCamera` on the same entity, unless set with `with_camera` (e.g. to drive
+/// the `CascadeShadowConfig` of a light).
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_rts_camera::{RtsCamera, ZoomDriven};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         RtsCamera::default(),
+///         // Narrow the field of view when zoomed in, for a more telephoto look
+///         ZoomDriven::<Projection>::fov(60f32.to_radians(), 35f32.to_radians()),
+///     ));
+/// }
+///
+/// # #[derive(Component)]
+/// # struct LevelOfDetail { bias: f32 }
+/// fn add_lod(mut commands: Commands, cam: Entity) {
+///     // Any component registered with `RtsCameraPlugin::with_zoom_driven`
+///     commands.spawn((
+///         LevelOfDetail { bias: 0.0 },
+///         ZoomDriven::lerp(2.0, 0.0, |lod: &mut LevelOfDetail, bias| lod.bias = bias)
+///             .with_camera(cam),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct ZoomDriven<C: Component> {
+    /// The camera whose zoom drives the component, or `None` for the camera on the same entity.
+    /// Defaults to `None`.
+    pub camera: Option<Entity>,
+    /// The easing applied to the zoom before it's passed on, e.g. to change the value more
+    /// towards one end of the zoom range.
+    /// Defaults to `EaseFunction::Linear`.
+    pub easing: EaseFunction,
+    apply: ZoomDrivenFn<C>,
+}
+
+/// Updates a component given the (eased) zoom.
+type ZoomDrivenFn<C> = Arc<dyn Fn(&mut C, f32) + Send + Sync>;
+
+impl<C: Component> ZoomDriven<C> {
+    /// Calls `apply` with the component and the zoom (see `RtsCamera.zoom`) whenever it changes.
+    pub fn new(apply: impl Fn(&mut C, f32) + Send + Sync + 'static) -> Self {
+        Self {
+            camera: None,
+            easing: EaseFunction::Linear,
+            apply: Arc::new(apply),
+        }
+    }
+
+    /// Interpolates between `zoomed_out` (at a zoom of `0.0`) and `zoomed_in` (at `1.0`), and
+    /// passes the result to `set`.
+    pub fn lerp<T: StableInterpolate + Send + Sync + 'static>(
+        zoomed_out: T,
+        zoomed_in: T,
+        set: impl Fn(&mut C, T) + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(move |component, zoom| {
+            set(component, zoomed_out.interpolate_stable(&zoomed_in, zoom));
+        })
+    }
+
+    /// Uses the zoom of `camera`, instead of the camera on the same entity.
+    pub fn with_camera(mut self, camera: Entity) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+
+    /// Sets the easing applied to the zoom.
+    pub fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+impl ZoomDriven<Projection> {
+    /// Interpolates the field of view of a perspective projection, in radians, between
+    /// `zoomed_out` and `zoomed_in`. Does nothing for orthographic projections.
+    pub fn fov(zoomed_out: f32, zoomed_in: f32) -> Self {
+        Self::lerp(zoomed_out, zoomed_in, |projection, fov| {
+            if let Projection::Perspective(perspective) = projection {
+                perspective.fov = fov;
+            }
+        })
+    }
+}
+
+// Not derived, as that would require `C: Clone`
+impl<C: Component> Clone for ZoomDriven<C> {
+    fn clone(&self) -> Self {
+        Self {
+            camera: self.camera,
+            easing: self.easing,
+            apply: self.apply.clone(),
+        }
+    }
+}
+
+impl<C: Component> std::fmt::Debug for ZoomDriven<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZoomDriven")
+            .field("camera", &self.camera)
+            .field("easing", &self.easing)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) fn apply_zoom_driven<C: Component>(
+    mut driven_q: Query<(Entity, &mut C, &ZoomDriven<C>)>,
+    cam_q: Query<&RtsCamera>,
+    // The zoom each component was last updated for
+    mut applied: Local<EntityHashMap<f32>>,
+) {
+    applied.retain(|entity, _| driven_q.contains(*entity));
+    for (entity, mut component, driven) in driven_q.iter_mut() {
+        let Ok(cam) = cam_q.get(driven.camera.unwrap_or(entity)) else {
+            continue;
+        };
+        if applied.get(&entity) == Some(&cam.zoom) {
+            continue;
+        }
+        applied.insert(entity, cam.zoom);
+        let zoom = EasingCurve::new(0.0, 1.0, driven.easing).sample_clamped(cam.zoom);
+        (driven.apply)(&mut component, zoom);
+    }
+}
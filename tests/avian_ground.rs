@@ -0,0 +1,28 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::{AvianGround, Ground, RtsCamera, RtsCameraPlugin};
+
+#[test]
+fn follows_ground_colliders() {
+    let mut app = RtsCameraTestApp::with_plugin(
+        RtsCameraPlugin::default().with_ground_backend::<AvianGround>(),
+    );
+    app.add_plugins(PhysicsPlugins::default());
+    app.world_mut().spawn((
+        RigidBody::Static,
+        Collider::cuboid(100.0, 1.0, 100.0),
+        Transform::from_xyz(0.0, 2.5, 0.0),
+        Ground,
+    ));
+    // Not ground, so it's ignored even though it's above the ground
+    app.world_mut().spawn((
+        RigidBody::Static,
+        Collider::cuboid(100.0, 1.0, 100.0),
+        Transform::from_xyz(0.0, 10.5, 0.0),
+    ));
+    let cam = app.spawn_camera(RtsCamera::default());
+    app.step(120);
+    assert_eq!(app.camera(cam).ground_point.y, 3.0);
+    assert_eq!(app.camera(cam).focus.translation.y, 3.0);
+}
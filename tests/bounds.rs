@@ -0,0 +1,49 @@
+use bevy::math::bounding::BoundingCircle;
+use bevy::prelude::*;
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::{CameraBounds, RtsCamera};
+
+#[test]
+fn target_is_kept_within_bounds() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera::default());
+    app.step(1);
+
+    // The default bounds are a square 20 units either side of the origin
+    app.camera_mut(cam).target_focus.translation = Vec3::new(100.0, 0.0, -50.0);
+    app.step(1);
+    assert_eq!(
+        app.camera(cam).target_focus.translation,
+        Vec3::new(20.0, 0.0, -20.0)
+    );
+
+    app.step(120);
+    assert_eq!(
+        app.camera(cam).focus.translation,
+        Vec3::new(20.0, 0.0, -20.0)
+    );
+}
+
+#[test]
+fn target_inside_bounds_is_left_alone() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera {
+        bounds: CameraBounds::from(BoundingCircle::new(Vec2::ZERO, 10.0)),
+        ..default()
+    });
+    app.step(1);
+
+    app.camera_mut(cam).target_focus.translation = Vec3::new(6.0, 0.0, 6.0);
+    app.step(1);
+    assert_eq!(
+        app.camera(cam).target_focus.translation,
+        Vec3::new(6.0, 0.0, 6.0)
+    );
+
+    app.camera_mut(cam).target_focus.translation = Vec3::new(30.0, 0.0, 0.0);
+    app.step(1);
+    assert_eq!(
+        app.camera(cam).target_focus.translation,
+        Vec3::new(10.0, 0.0, 0.0)
+    );
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::RtsCamera;
+
+#[test]
+fn parented_camera_resyncs_from_world_position() {
+    let mut app = RtsCameraTestApp::new();
+    // There's no transform propagation in the test app, so set the parent's global transform too
+    let parent_tfm = Transform::from_xyz(100.0, 0.0, 0.0);
+    let parent = app
+        .world_mut()
+        .spawn((parent_tfm, GlobalTransform::from(parent_tfm)))
+        .id();
+    let cam = app.spawn_camera(RtsCamera {
+        external_transform_control: true,
+        ..default()
+    });
+    app.world_mut().entity_mut(cam).set_parent(parent);
+    app.step(1);
+
+    // Something else moves the camera relative to its parent, then hands it back
+    *app.world_mut().get_mut::<Transform>(cam).unwrap() =
+        Transform::from_xyz(0.0, 20.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y);
+    app.camera_mut(cam).external_transform_control = false;
+    app.step(1);
+
+    let target = app.camera(cam).target_focus.translation;
+    assert!(
+        target.abs_diff_eq(Vec3::new(100.0, 0.0, 0.0), 1e-3),
+        "{target}"
+    );
+}
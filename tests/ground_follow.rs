@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::RtsCamera;
+
+#[test]
+fn focus_follows_ground_height() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera::default());
+    app.set_ground(|_| Some(5.0));
+    app.step(120);
+    assert_eq!(app.camera(cam).focus.translation.y, 5.0);
+
+    // A hill to the east
+    app.set_ground(|point| Some(if point.x > 10.0 { 8.0 } else { 0.0 }));
+    app.camera_mut(cam).target_focus.translation.x = 15.0;
+    app.step(120);
+    assert_eq!(app.camera(cam).focus.translation.y, 8.0);
+    assert_eq!(app.camera(cam).ground_point, Vec3::new(15.0, 8.0, 0.0));
+}
+
+#[test]
+fn ground_height_min_limits_focus_height() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera {
+        ground_height_min: Some(-2.0),
+        ..default()
+    });
+    // A deep trench
+    app.set_ground(|_| Some(-30.0));
+    app.step(120);
+    assert_eq!(app.camera(cam).focus.translation.y, -2.0);
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::RtsCamera;
+
+/// Returns how far `point` is from the line through the middle of the camera's view.
+fn distance_from_view_center(tfm: &Transform, point: Vec3) -> f32 {
+    (point - tfm.translation)
+        .reject_from(*tfm.forward())
+        .length()
+}
+
+#[test]
+fn camera_looks_at_look_target() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera {
+        target_focus: Transform::from_xyz(3.0, 0.0, -4.0).with_rotation(Quat::from_rotation_y(0.7)),
+        ..default()
+    });
+    app.step(1);
+
+    let tfm = app.camera_transform(cam);
+    let look_target = app.camera(cam).look_target();
+    assert_eq!(look_target, Vec3::new(3.0, 0.0, -4.0));
+    assert!(distance_from_view_center(&tfm, look_target) < 1e-4);
+}
+
+#[test]
+fn look_target_includes_look_offset() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera {
+        target_focus: Transform::from_xyz(3.0, 0.0, -4.0).with_rotation(Quat::from_rotation_y(0.7)),
+        look_offset: Vec3::new(2.0, 0.0, -5.0),
+        ..default()
+    });
+    app.step(1);
+
+    let tfm = app.camera_transform(cam);
+    let cam = app.camera(cam);
+    let look_target = cam.look_target();
+    assert_eq!(
+        look_target,
+        Vec3::new(3.0, 0.0, -4.0) + cam.focus.rotation * Vec3::new(2.0, 0.0, -5.0)
+    );
+    assert!(distance_from_view_center(&tfm, look_target) < 1e-4);
+    assert!(distance_from_view_center(&tfm, cam.ground_focus()) > 1.0);
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::{Ground, RapierGround, RtsCamera, RtsCameraPlugin};
+
+#[test]
+fn follows_ground_colliders() {
+    let mut app = RtsCameraTestApp::with_plugin(
+        RtsCameraPlugin::default().with_ground_backend::<RapierGround>(),
+    );
+    app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
+    app.world_mut().spawn((
+        Collider::cuboid(50.0, 0.5, 50.0),
+        Transform::from_xyz(0.0, 2.5, 0.0),
+        Ground,
+    ));
+    // Not ground, so it's ignored even though it's above the ground
+    app.world_mut().spawn((
+        Collider::cuboid(50.0, 0.5, 50.0),
+        Transform::from_xyz(0.0, 10.5, 0.0),
+    ));
+    let cam = app.spawn_camera(RtsCamera::default());
+    app.step(120);
+    assert_eq!(app.camera(cam).ground_point.y, 3.0);
+    assert_eq!(app.camera(cam).focus.translation.y, 3.0);
+}
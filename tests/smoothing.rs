@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use bevy_rts_camera::test_utils::RtsCameraTestApp;
+use bevy_rts_camera::RtsCamera;
+
+#[test]
+fn focus_eases_towards_target() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera::default());
+    app.step(1);
+
+    app.camera_mut(cam).target_focus.translation = Vec3::new(10.0, 0.0, 0.0);
+    app.step(1);
+    let x = app.camera(cam).focus.translation.x;
+    assert!(
+        x > 0.0 && x < 10.0,
+        "focus should be part way to the target, was at {x}"
+    );
+
+    app.step(1);
+    assert!(app.camera(cam).focus.translation.x > x);
+
+    app.step(120);
+    assert_eq!(app.camera(cam).focus.translation, Vec3::new(10.0, 0.0, 0.0));
+}
+
+#[test]
+fn zero_smoothness_reaches_target_immediately() {
+    let mut app = RtsCameraTestApp::new();
+    let cam = app.spawn_camera(RtsCamera {
+        pan_smoothness: 0.0,
+        zoom_smoothness: 0.0,
+        ..default()
+    });
+    app.step(1);
+
+    let mut camera = app.camera_mut(cam);
+    camera.target_focus.translation = Vec3::new(-5.0, 0.0, 3.0);
+    camera.target_zoom = 1.0;
+    app.step(1);
+    assert_eq!(app.camera(cam).focus.translation, Vec3::new(-5.0, 0.0, 3.0));
+    assert_eq!(app.camera(cam).zoom, 1.0);
+}
+
+#[test]
+fn camera_spawned_in_update_starts_at_target() {
+    let mut app = RtsCameraTestApp::new();
+    app.add_systems(
+        Update,
+        |mut commands: Commands, mut spawned: Local<bool>| {
+            if !*spawned {
+                *spawned = true;
+                commands.spawn(RtsCamera {
+                    target_focus: Transform::from_xyz(5.0, 0.0, -5.0),
+                    target_zoom: 0.5,
+                    ..default()
+                });
+            }
+        },
+    );
+    app.step(1);
+
+    // Snapped in the same frame, so it's never rendered from anywhere else
+    let (tfm, cam) = app
+        .world_mut()
+        .query::<(&Transform, &RtsCamera)>()
+        .single(app.world());
+    assert_eq!(cam.focus, cam.target_focus);
+    assert_eq!(cam.zoom, 0.5);
+    let expected = cam.focus.translation + Vec3::Y * cam.height_at_zoom(0.5);
+    assert_eq!(tfm.translation.xy(), expected.xy());
+}